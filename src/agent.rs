@@ -0,0 +1,275 @@
+//! Players that pick choices: uniformly random, greedy on the evaluation, the MCTS and a
+//! human at a terminal. Any two can play each other.
+
+use std::io::{self, BufRead, Write};
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+
+use crate::choices::Choice;
+use crate::mcts::{Game as MctsGame, MctsSettings};
+use crate::rules::{Game, TurnState};
+use crate::rollout::RolloutWeights;
+use crate::search::{SearchBudget, Searcher};
+
+/// What an agent does at its turn.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Decision {
+    Play(Choice),
+    /// Take back the agent's last choice and the replies to it.
+    Undo,
+    Resign,
+    /// Stop the game without a result.
+    Quit,
+}
+
+pub trait Agent {
+    /// Picks a choice for the active player of `game`, which must not be over.
+    fn choose(&mut self, game: &Game) -> Choice;
+
+    /// Decides what to do at the turn of the active player of `game`. Only people do more
+    /// than play a choice.
+    fn decide(&mut self, game: &Game) -> Decision {
+        Decision::Play(self.choose(game))
+    }
+
+    /// Describes the agent and its settings, e.g. for the player tags of a game record.
+    fn name(&self) -> String;
+}
+
+/// Plays uniformly at random among the legal choices.
+pub struct RandomAgent {
+    rng: StdRng,
+}
+
+impl RandomAgent {
+    pub fn new(seed: u64) -> Self {
+        RandomAgent { rng: StdRng::seed_from_u64(seed) }
+    }
+}
+
+impl Agent for RandomAgent {
+    fn choose(&mut self, game: &Game) -> Choice {
+        *game.get_all_choices().choose(&mut self.rng).unwrap()
+    }
+
+    fn name(&self) -> String {
+        String::from("Random")
+    }
+}
+
+/// Plays the choice whose resulting position evaluates best, picking at random among equals.
+pub struct GreedyAgent {
+    rng: StdRng,
+}
+
+impl GreedyAgent {
+    pub fn new(seed: u64) -> Self {
+        GreedyAgent { rng: StdRng::seed_from_u64(seed) }
+    }
+}
+
+impl Agent for GreedyAgent {
+    fn choose(&mut self, game: &Game) -> Choice {
+        let color = game.get_active_player_id();
+        let mut position = game.clone();
+        let mut best = vec![];
+        let mut best_value = f64::NEG_INFINITY;
+        for choice in game.get_all_choices() {
+            let undo = position.make(&choice);
+            let value = position.evaluate(color);
+            position.unmake(undo);
+            if value > best_value {
+                best_value = value;
+                best.clear();
+            }
+            if value == best_value {
+                best.push(choice);
+            }
+        }
+        *best.choose(&mut self.rng).unwrap()
+    }
+
+    fn name(&self) -> String {
+        String::from("Greedy")
+    }
+}
+
+/// Plays the choice the MCTS finds within its budget.
+pub struct MctsAgent {
+    searcher: Searcher,
+}
+
+impl MctsAgent {
+    pub fn new(budget: SearchBudget) -> Self {
+        MctsAgent::with_settings(budget, MctsSettings::default(), RolloutWeights::default())
+    }
+
+    pub fn with_settings(budget: SearchBudget, settings: MctsSettings, rollout: RolloutWeights) -> Self {
+        MctsAgent { searcher: Searcher::with_settings(budget, settings, rollout) }
+    }
+}
+
+impl Agent for MctsAgent {
+    fn choose(&mut self, game: &Game) -> Choice {
+        self.searcher.choose(game)
+    }
+
+    fn name(&self) -> String {
+        match self.searcher.rollout() {
+            rollout if rollout == RolloutWeights::default() => format!("MCTS {}", self.searcher.budget),
+            rollout => format!("MCTS {}, rollout {}", self.searcher.budget, rollout),
+        }
+    }
+}
+
+/// The commands of a person at their turn.
+pub const HELP: &str = "\
+Enter a choice in notation (e.g. +M, S>UL, M:f6>UR2) or its number from `list`.
+Commands:
+  list     show every legal choice with its number
+  board    show the board again
+  undo     take back your last choice and the replies to it
+  resign   give up the game
+  help     show this message";
+
+enum Command {
+    Play(Choice),
+    List,
+    Board,
+    Undo,
+    Resign,
+    Help,
+}
+
+fn parse_command(line: &str, choices: &[Choice]) -> Result<Command, String> {
+    match line {
+        "list" => return Ok(Command::List),
+        "board" => return Ok(Command::Board),
+        "undo" => return Ok(Command::Undo),
+        "resign" => return Ok(Command::Resign),
+        "help" | "?" => return Ok(Command::Help),
+        _ => {}
+    }
+    if let Ok(number) = line.parse::<usize>() {
+        return match number.checked_sub(1).and_then(|index| choices.get(index)) {
+            Some(choice) => Ok(Command::Play(*choice)),
+            None => Err(format!("There is no choice number {}", number)),
+        };
+    }
+    line.parse().map(Command::Play).map_err(|error| format!("{}", error))
+}
+
+fn describe_turn(turn_state: &TurnState) -> &'static str {
+    match turn_state {
+        TurnState::WhiteFirstAction | TurnState::BlackFirstAction => "first action",
+        _ => "second action",
+    }
+}
+
+/// Where a person's commands come from, a line at a time.
+pub trait LineInput {
+    /// Appends the next line to `line`, returning its length in bytes, 0 at the end.
+    fn read_line(&mut self, line: &mut String) -> io::Result<usize>;
+}
+
+impl<R: BufRead> LineInput for R {
+    fn read_line(&mut self, line: &mut String) -> io::Result<usize> {
+        BufRead::read_line(self, line)
+    }
+}
+
+/// Standard input, locked only while a line is read, so that any number of people at one
+/// terminal can share it.
+pub struct SharedStdin;
+
+impl LineInput for SharedStdin {
+    fn read_line(&mut self, line: &mut String) -> io::Result<usize> {
+        io::stdin().read_line(line)
+    }
+}
+
+/// Asks a person for every choice, in notation or by its number in `list`, with the commands
+/// in `HELP`. Quits when the input ends.
+pub struct HumanAgent<R, W> {
+    input: R,
+    output: W,
+    /// Whether `HELP` was shown, before the first turn.
+    greeted: bool,
+}
+
+impl HumanAgent<SharedStdin, io::Stdout> {
+    pub fn stdin() -> Self {
+        HumanAgent::new(SharedStdin, io::stdout())
+    }
+}
+
+impl<R: LineInput, W: Write> HumanAgent<R, W> {
+    pub fn new(input: R, output: W) -> Self {
+        HumanAgent { input, output, greeted: false }
+    }
+
+    /// Reads the next command, `None` at the end of the input.
+    fn read_line(&mut self, game: &Game) -> Option<String> {
+        write!(self.output, "{:?} to play, {}> ", game.get_active_player_id(), describe_turn(&game.turn_state)).ok()?;
+        self.output.flush().ok()?;
+        let mut line = String::new();
+        match self.input.read_line(&mut line) {
+            Ok(0) | Err(_) => {
+                let _ = writeln!(self.output);
+                None
+            }
+            Ok(_) => Some(line.trim().to_string()),
+        }
+    }
+}
+
+impl<R: LineInput, W: Write> Agent for HumanAgent<R, W> {
+    /// Passes when the input ends. Taking back and resigning are left to `decide`.
+    fn choose(&mut self, game: &Game) -> Choice {
+        loop {
+            match self.decide(game) {
+                Decision::Play(choice) => return choice,
+                Decision::Quit => return Choice::Pass,
+                Decision::Undo | Decision::Resign => {
+                    let _ = writeln!(self.output, "This game cannot be taken back or resigned");
+                }
+            }
+        }
+    }
+
+    fn decide(&mut self, game: &Game) -> Decision {
+        if !self.greeted {
+            let _ = writeln!(self.output, "{}", HELP);
+            self.greeted = true;
+        }
+        let choices = game.get_all_choices();
+        loop {
+            let line = match self.read_line(game) {
+                Some(line) => line,
+                None => return Decision::Quit,
+            };
+            let message = match parse_command(&line, &choices) {
+                Ok(Command::Play(choice)) => match game.validate_choice(&choice) {
+                    Ok(()) => return Decision::Play(choice),
+                    Err(reason) => format!("Illegal choice: {}", reason),
+                },
+                Ok(Command::List) => {
+                    let list: Vec<String> = choices.iter().enumerate().map(|(index, choice)| format!("{:>4}. {}", index + 1, choice)).collect();
+                    list.join("\n")
+                }
+                Ok(Command::Board) => game.board.to_string(),
+                Ok(Command::Undo) => return Decision::Undo,
+                Ok(Command::Resign) => return Decision::Resign,
+                Ok(Command::Help) => HELP.to_string(),
+                Err(message) => message,
+            };
+            let _ = writeln!(self.output, "{}", message);
+        }
+    }
+
+    fn name(&self) -> String {
+        String::from("Human")
+    }
+}
@@ -0,0 +1,214 @@
+use std::time::Duration;
+
+use rokku::{Color, MctsSettings, RolloutWeights, RuleSet, SearchBudget};
+
+pub const USAGE: &str = "\
+Usage: rokku <command> [options]
+
+Commands:
+  play <white|black>      play against the AI, or the agent of --opponent
+  selfplay                let the AI play itself
+  analyze <position>      search a position and print the best choice
+  replay <file>           replay and verify a game record
+  perft <depth>           count the positions reachable in <depth> choices
+  tournament              play two agents or search settings against each other
+
+Options:
+  --agent <kind>              selfplay and tournament: mcts, random, greedy or human for White
+                              or the first player (default: mcts)
+  --opponent <kind>           play: the agent to play against (default: mcts)
+                              selfplay and tournament: the agent for Black or the second player
+                              (default: the same as --agent)
+  --rules <rules>             base, all or a comma-separated list of variants and rulings,
+                              optionally with max-turns=<n> (default: all, and for selfplay
+                              and tournament max-turns=200 unless given)
+  --iterations <n>            most search iterations per choice (default: 1000000 without a
+                              time limit)
+  --time <seconds>            search time per choice
+  --turn-time <seconds>       search time per turn, shared by its two choices
+  --exploration <c>           weight of exploration in the search (default: 1.414)
+  --no-tree-reuse             start every search from a new tree
+  --playout-depth <n|full>    choices a playout plays before the position is evaluated
+                              (default: 25)
+  --rollout <weights>         playout weights as a comma-separated list of uniform,
+                              bad-rock, good-rocks, material, daimyo, edge or win=<weight>
+                              and candidates=<n>, the choices weighed at every step
+  --opponent-rollout <weights>  tournament: playout weights for the second player
+  --opponent-iterations <n>   tournament: iterations for the second player
+  --opponent-time <seconds>   tournament: search time per choice for the second player
+  --opponent-turn-time <seconds>  tournament: search time per turn for the second player
+  --games <n>                 tournament: number of games (default: 10)
+  --random-opening <n>        play <n> random choices before the AI takes over (default: 0)
+  --seed <n>                  seed for the random opening and the search (default: from the
+                              clock)
+  --position <position>       perft: start from this position instead of the initial one
+  --divide                    perft: print the count below each choice of the position
+  --format <text|notation|record>  output format (default: text)
+  --record <file>             write the game record to <file>
+  --comments                  explain every choice in a comment of the game record";
+
+/// The turn limit of selfplay and tournament games when the rules set none.
+const SELF_PLAY_MAX_TURNS: u32 = 200;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Format {
+    /// Boards and commentary for people watching.
+    Text,
+    /// Only choices and positions in notation.
+    Notation,
+    /// Only the game record.
+    Record,
+}
+
+/// The players `--agent` and `--opponent` choose between.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AgentKind {
+    Mcts,
+    Random,
+    Greedy,
+    Human,
+}
+
+pub enum Command {
+    Play(Color),
+    SelfPlay,
+    Analyze(String),
+    Replay(String),
+    Perft(u32),
+    Tournament,
+}
+
+pub struct Options {
+    pub rules: RuleSet,
+    pub agent: AgentKind,
+    pub opponent: Option<AgentKind>,
+    pub limit: SearchBudget,
+    pub opponent_limit: Option<SearchBudget>,
+    pub mcts: MctsSettings,
+    pub rollout: RolloutWeights,
+    pub opponent_rollout: Option<RolloutWeights>,
+    pub games: usize,
+    pub random_opening: usize,
+    pub seed: u64,
+    pub position: Option<String>,
+    pub divide: bool,
+    pub format: Format,
+    pub record_path: Option<String>,
+    pub comments: bool,
+}
+
+fn parse_number<T: std::str::FromStr>(option: &str, value: Option<String>) -> Result<T, String> {
+    let value = value.ok_or_else(|| format!("Missing value for {}", option))?;
+    value.parse().map_err(|_| format!("Invalid value '{}' for {}", value, option))
+}
+
+fn parse_seconds(option: &str, value: Option<String>) -> Result<Duration, String> {
+    let seconds: f64 = parse_number(option, value)?;
+    Duration::try_from_secs_f64(seconds).map_err(|_| format!("Invalid time for {}", option))
+}
+
+fn parse_agent(option: &str, value: Option<String>) -> Result<AgentKind, String> {
+    match value.as_deref() {
+        Some("mcts") => Ok(AgentKind::Mcts),
+        Some("random") => Ok(AgentKind::Random),
+        Some("greedy") => Ok(AgentKind::Greedy),
+        Some("human") => Ok(AgentKind::Human),
+        _ => Err(format!("{} expects mcts, random, greedy or human", option)),
+    }
+}
+
+fn opponent_limit(options: &mut Options) -> &mut SearchBudget {
+    options.opponent_limit.get_or_insert(SearchBudget { per_action: None, per_turn: None, max_iterations: None })
+}
+
+pub fn parse_args(mut args: impl Iterator<Item = String>) -> Result<(Command, Options), String> {
+    let command = match args.next().as_deref() {
+        Some("play") => match args.next().as_deref() {
+            Some("white") => Command::Play(Color::White),
+            Some("black") => Command::Play(Color::Black),
+            _ => return Err(String::from("play expects white or black")),
+        },
+        Some("selfplay") => Command::SelfPlay,
+        Some("analyze") => Command::Analyze(args.next().ok_or("analyze expects a position")?),
+        Some("replay") => Command::Replay(args.next().ok_or("replay expects a file")?),
+        Some("perft") => Command::Perft(parse_number("perft", args.next())?),
+        Some("tournament") => Command::Tournament,
+        Some(command) => return Err(format!("Unknown command '{}'", command)),
+        None => return Err(String::from("Missing command")),
+    };
+
+    let mut options = Options {
+        rules: RuleSet::default(),
+        agent: AgentKind::Mcts,
+        opponent: None,
+        limit: SearchBudget { per_action: None, per_turn: None, max_iterations: None },
+        opponent_limit: None,
+        mcts: MctsSettings::default(),
+        rollout: RolloutWeights::default(),
+        opponent_rollout: None,
+        games: 10,
+        random_opening: 0,
+        seed: std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_nanos() as u64)
+            .unwrap_or_default(),
+        position: None,
+        divide: false,
+        format: Format::Text,
+        record_path: None,
+        comments: false,
+    };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--rules" => {
+                let value = args.next().ok_or("Missing value for --rules")?;
+                options.rules = value.parse()?;
+            }
+            "--agent" => options.agent = parse_agent(&arg, args.next())?,
+            "--opponent" => options.opponent = Some(parse_agent(&arg, args.next())?),
+            "--iterations" => options.limit.max_iterations = Some(parse_number(&arg, args.next())?),
+            "--time" => options.limit.per_action = Some(parse_seconds(&arg, args.next())?),
+            "--turn-time" => options.limit.per_turn = Some(parse_seconds(&arg, args.next())?),
+            "--exploration" => options.mcts.exploration = parse_number(&arg, args.next())?,
+            "--no-tree-reuse" => options.mcts.reuse_tree = false,
+            "--playout-depth" => {
+                options.mcts.playout_depth = match args.next() {
+                    Some(value) if value == "full" => None,
+                    value => Some(parse_number(&arg, value)?),
+                };
+            }
+            "--rollout" => options.rollout = args.next().ok_or("Missing value for --rollout")?.parse()?,
+            "--opponent-rollout" => options.opponent_rollout = Some(args.next().ok_or("Missing value for --opponent-rollout")?.parse()?),
+            "--opponent-iterations" => opponent_limit(&mut options).max_iterations = Some(parse_number(&arg, args.next())?),
+            "--opponent-time" => opponent_limit(&mut options).per_action = Some(parse_seconds(&arg, args.next())?),
+            "--opponent-turn-time" => opponent_limit(&mut options).per_turn = Some(parse_seconds(&arg, args.next())?),
+            "--games" => options.games = parse_number(&arg, args.next())?,
+            "--random-opening" => options.random_opening = parse_number(&arg, args.next())?,
+            "--seed" => options.seed = parse_number(&arg, args.next())?,
+            "--position" => options.position = Some(args.next().ok_or("Missing value for --position")?),
+            "--divide" => options.divide = true,
+            "--format" => {
+                options.format = match args.next().as_deref() {
+                    Some("text") => Format::Text,
+                    Some("notation") => Format::Notation,
+                    Some("record") => Format::Record,
+                    _ => return Err(String::from("--format expects text, notation or record")),
+                };
+            }
+            "--record" => options.record_path = Some(args.next().ok_or("Missing value for --record")?),
+            "--comments" => options.comments = true,
+            _ => return Err(format!("Unknown argument '{}'", arg)),
+        }
+    }
+    for limit in [Some(&mut options.limit), options.opponent_limit.as_mut()].into_iter().flatten() {
+        if limit.per_action.is_none() && limit.per_turn.is_none() && limit.max_iterations.is_none() {
+            *limit = SearchBudget::default();
+        }
+    }
+    // Games between agents may shuffle forever without a limit
+    if matches!(command, Command::SelfPlay | Command::Tournament) && options.rules.max_turns.is_none() {
+        options.rules.max_turns = Some(SELF_PLAY_MAX_TURNS);
+    }
+    options.mcts.seed = options.seed;
+    Ok((command, options))
+}
@@ -0,0 +1,126 @@
+mod cli;
+mod play;
+mod selfplay;
+
+use rokku::mcts::Game as MctsGame;
+
+use rokku::{divide, perft_counts, Agent, Color, Game, GameRecord, HumanAgent, Searcher};
+
+use cli::{AgentKind, Command, Format, Options};
+
+fn load_position(position: &str, options: &Options) -> Result<Game, String> {
+    let mut game = Game::from_notation(position).map_err(|error| format!("Invalid position: {}", error))?;
+    game.rules = options.rules;
+    Ok(game)
+}
+
+fn analyze(position: &str, options: &Options) -> Result<(), String> {
+    let game = load_position(position, options)?;
+    if game.is_terminal() {
+        return Err(String::from("The game is already over"));
+    }
+    let result = Searcher::with_settings(options.limit, options.mcts, options.rollout).search(&game);
+    match options.format {
+        Format::Text => {
+            game.board.print();
+            println!("{} legal choices for {:?}", game.get_all_choices().len(), game.get_active_player_id());
+            for child in result.children.iter().take(10) {
+                println!("{:>12} {:>8} visits {:>6.1}%", child.choice.to_string(), child.visits, 100.0 * child.value);
+            }
+            println!("Best choice after {} iterations ({}): {}", result.iterations, options.limit, result.choice);
+        }
+        Format::Notation | Format::Record => println!("{}", result.choice),
+    }
+    Ok(())
+}
+
+fn replay(path: &str, options: &Options) -> Result<(), String> {
+    let text = std::fs::read_to_string(path).map_err(|error| format!("Could not read {}: {}", path, error))?;
+    let record: GameRecord = text.parse().map_err(|error| format!("Invalid game record: {}", error))?;
+    let game = record.replay().map_err(|error| format!("Invalid game: {}", error))?;
+    match options.format {
+        Format::Text => {
+            let mut game = record.start_game().map_err(|error| format!("{}", error))?;
+            game.board.print();
+            for choice in record.choices.iter() {
+                println!("{} - {}", choice, game.explain_choice(choice));
+                game.apply_choice(choice);
+                game.board.print();
+                println!("------");
+            }
+            println!("Result: {}", record.result);
+        }
+        Format::Notation => println!("{}", game.to_notation()),
+        Format::Record => print!("{}", record),
+    }
+    Ok(())
+}
+
+fn run_perft(depth: u32, options: &Options) -> Result<(), String> {
+    let mut game = match &options.position {
+        Some(position) => load_position(position, options)?,
+        None => Game::with_rules(options.rules),
+    };
+    if options.divide {
+        for (choice, nodes) in divide(&mut game, depth) {
+            println!("{}: {}", choice, nodes);
+        }
+        return Ok(());
+    }
+    for depth in 1..=depth {
+        let start = std::time::Instant::now();
+        let counts = perft_counts(&mut game, depth);
+        println!("perft({}) = {} ({:.2?})", depth, counts, start.elapsed());
+    }
+    Ok(())
+}
+
+fn write_record(record: &GameRecord, options: &Options) -> Result<(), String> {
+    match options.format {
+        Format::Text | Format::Record => println!("{}", record),
+        Format::Notation => {}
+    }
+    if let Some(path) = &options.record_path {
+        std::fs::write(path, record.to_string())
+            .map_err(|error| format!("Could not write the game record to {}: {}", path, error))?;
+    }
+    Ok(())
+}
+
+fn run(command: Command, options: Options) -> Result<(), String> {
+    match command {
+        Command::Play(human) => {
+            let human_agent: Box<dyn Agent> = Box::new(HumanAgent::stdin());
+            let kind = options.opponent.unwrap_or(AgentKind::Mcts);
+            let opponent = selfplay::make_agent(kind, options.limit, options.mcts, options.rollout, options.seed);
+            let (white, black) = match human {
+                Color::White => (human_agent, opponent),
+                Color::Black => (opponent, human_agent),
+            };
+            write_record(&play::play(options.rules, white, black, options.comments), &options)
+        }
+        Command::SelfPlay => write_record(&selfplay::self_play(&options), &options),
+        Command::Analyze(position) => analyze(&position, &options),
+        Command::Replay(path) => replay(&path, &options),
+        Command::Perft(depth) => run_perft(depth, &options),
+        Command::Tournament => {
+            selfplay::tournament(&options);
+            Ok(())
+        }
+    }
+}
+
+fn main() {
+    let (command, options) = match cli::parse_args(std::env::args().skip(1)) {
+        Ok(parsed) => parsed,
+        Err(message) => {
+            eprintln!("{}", message);
+            eprintln!("{}", cli::USAGE);
+            std::process::exit(2);
+        }
+    };
+    if let Err(message) = run(command, options) {
+        eprintln!("{}", message);
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,71 @@
+use rokku::mcts::Game as MctsGame;
+
+use rokku::{Agent, Choice, Color, Decision, Game, GameRecord, GameResult, RuleSet, TurnState, Undo};
+
+fn print_result(game: &Game) {
+    if let TurnState::Over(outcome) = game.turn_state {
+        println!("{}", outcome);
+    }
+}
+
+/// Plays a game between `white` and `black`, showing the board after every choice, and
+/// returns the record of the game.
+pub fn play(rules: RuleSet, mut white: Box<dyn Agent>, mut black: Box<dyn Agent>, comments: bool) -> GameRecord {
+    let mut game = Game::with_rules(rules);
+    let mut record = GameRecord::new(&game);
+    record.set_tag("White", &white.name());
+    record.set_tag("Black", &black.name());
+    // The player and the undo of every choice so far
+    let mut history: Vec<(Color, Undo)> = vec![];
+
+    game.board.print();
+    while !game.is_terminal() {
+        let color = game.get_active_player_id();
+        let agent = match color {
+            Color::White => &mut white,
+            Color::Black => &mut black,
+        };
+        let choice = match agent.decide(&game) {
+            Decision::Play(choice) => choice,
+            Decision::Undo => {
+                // Back to before the player's last choice, with the replies to it
+                let last_own_choice = match history.iter().rposition(|(player, _)| *player == color) {
+                    Some(index) => index,
+                    None => {
+                        println!("There is nothing to take back");
+                        continue;
+                    }
+                };
+                let mut undone = vec![];
+                while history.len() > last_own_choice {
+                    let (_, undo) = history.pop().unwrap();
+                    game.unmake(undo);
+                    undone.extend(record.pop());
+                }
+                let undone: Vec<String> = undone.iter().rev().map(Choice::to_string).collect();
+                println!("Took back {}", undone.join(", "));
+                game.board.print();
+                continue;
+            }
+            Decision::Resign => {
+                record.result = GameResult::won_by(color.opposite());
+                record.set_tag("Termination", &format!("{:?} resigned", color));
+                println!("{:?} resigns", color);
+                break;
+            }
+            Decision::Quit => break,
+        };
+
+        println!("{:?} plays {}", color, choice);
+        let explanation = game.explain_choice(&choice);
+        println!("{}", explanation);
+        history.push((color, game.make(&choice)));
+        record.push(choice, &game);
+        if comments {
+            record.comment(&explanation);
+        }
+        game.board.print();
+    }
+    print_result(&game);
+    record
+}
@@ -0,0 +1,124 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+
+use rokku::mcts::Game as MctsGame;
+use rokku::{Agent, Color, Game, GameRecord, GameResult, GreedyAgent, HumanAgent, MctsAgent, MctsSettings, RandomAgent, RolloutWeights, SearchBudget};
+
+use crate::cli::{AgentKind, Format, Options};
+
+pub fn make_agent(kind: AgentKind, budget: SearchBudget, settings: MctsSettings, rollout: RolloutWeights, seed: u64) -> Box<dyn Agent> {
+    match kind {
+        AgentKind::Mcts => Box::new(MctsAgent::with_settings(budget, MctsSettings { seed, ..settings }, rollout)),
+        AgentKind::Random => Box::new(RandomAgent::new(seed)),
+        AgentKind::Greedy => Box::new(GreedyAgent::new(seed)),
+        AgentKind::Human => Box::new(HumanAgent::stdin()),
+    }
+}
+
+/// Plays `count` uniformly random choices, for varied openings.
+fn play_random_opening(game: &mut Game, record: &mut GameRecord, count: usize, comments: bool, rng: &mut StdRng) {
+    for _ in 0..count {
+        if game.is_terminal() {
+            break;
+        }
+        let choice = *game.get_all_choices().choose(rng).unwrap();
+        let explanation = game.explain_choice(&choice);
+        game.apply_choice(&choice);
+        record.push(choice, game);
+        if comments {
+            record.comment(&explanation);
+        }
+    }
+}
+
+fn play_game(options: &Options, white: &mut dyn Agent, black: &mut dyn Agent, format: Format, rng: &mut StdRng) -> GameRecord {
+    let mut game = Game::with_rules(options.rules);
+    let mut record = GameRecord::new(&game);
+    record.set_tag("White", &white.name());
+    record.set_tag("Black", &black.name());
+    if options.random_opening > 0 {
+        record.set_tag("RandomOpening", &options.random_opening.to_string());
+    }
+    play_random_opening(&mut game, &mut record, options.random_opening, options.comments, rng);
+
+    if format == Format::Text {
+        game.board.print();
+        println!("------");
+    }
+    while !game.is_terminal() {
+        let color = game.get_active_player_id();
+        let choice = match color {
+            Color::White => white.choose(&game),
+            Color::Black => black.choose(&game),
+        };
+        let explanation = game.explain_choice(&choice);
+        game.apply_choice(&choice);
+        record.push(choice, &game);
+        if options.comments {
+            record.comment(&explanation);
+        }
+
+        match format {
+            Format::Text => {
+                println!("{} - {}", choice, explanation);
+                game.board.print();
+                println!("------");
+            }
+            Format::Notation => println!("{}", choice),
+            Format::Record => {}
+        }
+    }
+    record
+}
+
+pub fn self_play(options: &Options) -> GameRecord {
+    let mut rng = StdRng::seed_from_u64(options.seed);
+    let opponent_limit = options.opponent_limit.unwrap_or(options.limit);
+    let opponent_rollout = options.opponent_rollout.unwrap_or(options.rollout);
+    let mut white = make_agent(options.agent, options.limit, options.mcts, options.rollout, options.seed);
+    let mut black = make_agent(options.opponent.unwrap_or(options.agent), opponent_limit, options.mcts, opponent_rollout, options.seed.wrapping_add(1));
+    let mut record = play_game(options, &mut *white, &mut *black, options.format, &mut rng);
+    record.set_tag("Seed", &options.seed.to_string());
+    record
+}
+
+/// Plays pairs of games between the two agents with the colors swapped and the same random
+/// opening, then reports the score of the first agent.
+pub fn tournament(options: &Options) {
+    let opponent_kind = options.opponent.unwrap_or(options.agent);
+    let opponent_limit = options.opponent_limit.unwrap_or(options.limit);
+    let opponent_rollout = options.opponent_rollout.unwrap_or(options.rollout);
+    let mut challenger = make_agent(options.agent, options.limit, options.mcts, options.rollout, options.seed);
+    let mut opponent = make_agent(opponent_kind, opponent_limit, options.mcts, opponent_rollout, options.seed.wrapping_add(1));
+    let (challenger_name, opponent_name) = (challenger.name(), opponent.name());
+    let (mut wins, mut draws, mut losses) = (0, 0, 0);
+    for game_number in 0..options.games {
+        let pair_seed = options.seed.wrapping_add((game_number / 2) as u64);
+        let mut rng = StdRng::seed_from_u64(pair_seed);
+        let challenger_color = if game_number % 2 == 0 { Color::White } else { Color::Black };
+        let record = match challenger_color {
+            Color::White => play_game(options, &mut *challenger, &mut *opponent, Format::Record, &mut rng),
+            Color::Black => play_game(options, &mut *opponent, &mut *challenger, Format::Record, &mut rng),
+        };
+        match record.result {
+            GameResult::WhiteWins if challenger_color == Color::White => wins += 1,
+            GameResult::BlackWins if challenger_color == Color::Black => wins += 1,
+            GameResult::WhiteWins | GameResult::BlackWins => losses += 1,
+            GameResult::Draw | GameResult::Unfinished => draws += 1,
+        }
+        match options.format {
+            Format::Record => println!("{}", record),
+            _ => println!("Game {}: {} ({} as {:?})", game_number + 1, record.result, challenger_name, challenger_color),
+        }
+    }
+    println!(
+        "{} vs {}: {} wins, {} draws, {} losses ({:.1}%)",
+        challenger_name,
+        opponent_name,
+        wins,
+        draws,
+        losses,
+        100.0 * (wins as f64 + 0.5 * draws as f64) / options.games.max(1) as f64,
+    );
+}
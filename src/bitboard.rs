@@ -0,0 +1,209 @@
+//! Bitboards over the 91 spaces of the board.
+//!
+//! Spaces are numbered from 0 to 90 in order of `q`, then `r`, the order the board has always
+//! been scanned in, so iterating a bitboard visits spaces in that order. The neighbor and ray
+//! tables are built at compile time and replace coordinate arithmetic and off-board checks in
+//! move generation.
+
+use std::ops::{BitAnd, BitOr, Not};
+
+use crate::coordinates::{Coordinates, Direction};
+
+pub const CELLS: usize = 91;
+
+/// The neighbor of a space on the edge of the board, in the direction off it.
+pub const NO_CELL: u8 = u8::MAX;
+
+const DIRECTIONS: [Direction; 6] = [
+    Direction::Left,
+    Direction::UpLeft,
+    Direction::UpRight,
+    Direction::Right,
+    Direction::DownRight,
+    Direction::DownLeft,
+];
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Bitboard(pub u128);
+
+impl Bitboard {
+    pub const EMPTY: Bitboard = Bitboard(0);
+    pub const FULL: Bitboard = Bitboard((1 << CELLS) - 1);
+
+    pub fn single(cell: usize) -> Self {
+        Bitboard(1 << cell)
+    }
+
+    pub fn contains(&self, cell: usize) -> bool {
+        self.0 & (1 << cell) != 0
+    }
+
+    pub fn insert(&mut self, cell: usize) {
+        self.0 |= 1 << cell;
+    }
+
+    pub fn remove(&mut self, cell: usize) {
+        self.0 &= !(1 << cell);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn count(&self) -> u32 {
+        self.0.count_ones()
+    }
+
+    /// The space of the bitboard closest to where a ray in `direction` starts.
+    pub fn nearest(&self, direction: Direction) -> Option<usize> {
+        if self.is_empty() {
+            return None;
+        }
+        // Cells are numbered along q, then r, so these directions count up
+        match direction {
+            Direction::UpRight | Direction::Right | Direction::DownRight => Some(self.0.trailing_zeros() as usize),
+            Direction::Left | Direction::UpLeft | Direction::DownLeft => Some(127 - self.0.leading_zeros() as usize),
+        }
+    }
+
+    /// The spaces in the bitboard, in order.
+    pub fn cells(self) -> impl Iterator<Item = usize> {
+        let mut bits = self.0;
+        std::iter::from_fn(move || {
+            if bits == 0 {
+                return None;
+            }
+            let cell = bits.trailing_zeros() as usize;
+            bits &= bits - 1;
+            Some(cell)
+        })
+    }
+}
+
+impl BitAnd for Bitboard {
+    type Output = Bitboard;
+
+    fn bitand(self, rhs: Bitboard) -> Bitboard {
+        Bitboard(self.0 & rhs.0)
+    }
+}
+
+impl BitOr for Bitboard {
+    type Output = Bitboard;
+
+    fn bitor(self, rhs: Bitboard) -> Bitboard {
+        Bitboard(self.0 | rhs.0)
+    }
+}
+
+impl Not for Bitboard {
+    type Output = Bitboard;
+
+    fn not(self) -> Bitboard {
+        Bitboard(!self.0 & Bitboard::FULL.0)
+    }
+}
+
+struct Tables {
+    coordinates: [Coordinates; CELLS],
+    /// Cell of every `[q + 5][r + 5]`, `NO_CELL` off the board.
+    cells: [[u8; 11]; 11],
+    neighbors: [[u8; 6]; CELLS],
+    /// Every space beyond a cell in a direction, up to the edge.
+    rays: [[Bitboard; 6]; CELLS],
+}
+
+const fn step(coordinates: Coordinates, direction: usize) -> Coordinates {
+    let Coordinates(q, r, s) = coordinates;
+    match direction {
+        0 => Coordinates(q - 1, r, s + 1),
+        1 => Coordinates(q, r - 1, s + 1),
+        2 => Coordinates(q + 1, r - 1, s),
+        3 => Coordinates(q + 1, r, s - 1),
+        4 => Coordinates(q, r + 1, s - 1),
+        _ => Coordinates(q - 1, r + 1, s),
+    }
+}
+
+const fn on_board(coordinates: Coordinates) -> bool {
+    coordinates.0 >= -5 && coordinates.0 <= 5 && coordinates.1 >= -5 && coordinates.1 <= 5 && coordinates.2 >= -5 && coordinates.2 <= 5
+}
+
+const fn generate_tables() -> Tables {
+    let mut tables = Tables {
+        coordinates: [Coordinates(0, 0, 0); CELLS],
+        cells: [[NO_CELL; 11]; 11],
+        neighbors: [[NO_CELL; 6]; CELLS],
+        rays: [[Bitboard(0); 6]; CELLS],
+    };
+
+    let mut cell = 0;
+    let mut q = -5;
+    while q <= 5 {
+        let mut r = -5;
+        while r <= 5 {
+            let coordinates = Coordinates(q, r, -q - r);
+            if on_board(coordinates) {
+                tables.coordinates[cell] = coordinates;
+                tables.cells[(q + 5) as usize][(r + 5) as usize] = cell as u8;
+                cell += 1;
+            }
+            r += 1;
+        }
+        q += 1;
+    }
+
+    let mut cell = 0;
+    while cell < CELLS {
+        let mut direction = 0;
+        while direction < 6 {
+            let mut next = step(tables.coordinates[cell], direction);
+            if on_board(next) {
+                tables.neighbors[cell][direction] = tables.cells[(next.0 + 5) as usize][(next.1 + 5) as usize];
+            }
+            while on_board(next) {
+                tables.rays[cell][direction].0 |= 1 << tables.cells[(next.0 + 5) as usize][(next.1 + 5) as usize];
+                next = step(next, direction);
+            }
+            direction += 1;
+        }
+        cell += 1;
+    }
+    tables
+}
+
+static TABLES: Tables = generate_tables();
+
+/// The cell of a space, `None` off the board.
+pub fn cell(coordinates: Coordinates) -> Option<usize> {
+    if coordinates.is_off_board() {
+        return None;
+    }
+    Some(TABLES.cells[(coordinates.0 + 5) as usize][(coordinates.1 + 5) as usize] as usize)
+}
+
+pub fn coordinates(cell: usize) -> Coordinates {
+    TABLES.coordinates[cell]
+}
+
+/// The next cell in `direction`, `None` off the edge.
+pub fn neighbor(cell: usize, direction: Direction) -> Option<usize> {
+    match TABLES.neighbors[cell][direction as usize] {
+        NO_CELL => None,
+        neighbor => Some(neighbor as usize),
+    }
+}
+
+/// The cell `steps` spaces away in `direction`, `None` off the edge.
+pub fn cell_at_distance(cell: usize, direction: Direction, steps: usize) -> Option<usize> {
+    (0..steps).try_fold(cell, |cell, _| neighbor(cell, direction))
+}
+
+pub fn neighbors(cell: usize) -> impl Iterator<Item = (Direction, usize)> {
+    DIRECTIONS.into_iter().filter_map(move |direction| neighbor(cell, direction).map(|neighbor| (direction, neighbor)))
+}
+
+/// Every cell beyond `cell` in `direction`, up to the edge.
+pub fn ray(cell: usize, direction: Direction) -> Bitboard {
+    TABLES.rays[cell][direction as usize]
+}
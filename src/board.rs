@@ -1,21 +1,214 @@
+use strum_macros::EnumIter;
+
+use crate::bitboard::{self, Bitboard, CELLS, NO_CELL};
+use crate::coordinates::{Coordinates, Direction, CENTER_SPACE};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Space {
+    Empty,
+    Occupied(Piece),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Piece {
+    GoodRock,
+    GoodRock2,
+    BadRock,
+    Token(Color, Token),
+}
+
+/// The number of distinct pieces: the three rocks and seven tokens of each player.
+pub(crate) const PIECES: usize = 3 + 2 * TOKENS;
+pub(crate) const TOKENS: usize = 7;
+
+impl Piece {
+    /// A distinct number below `PIECES` for every piece.
+    pub(crate) fn index(&self) -> usize {
+        match self {
+            Piece::GoodRock => 0,
+            Piece::GoodRock2 => 1,
+            Piece::BadRock => 2,
+            Piece::Token(color, token) => 3 + *color as usize * TOKENS + *token as usize,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Color {
+    White = 0,
+    Black = 1,
+}
+
+impl Color {
+    pub fn opposite(&self) -> Self {
+        match self {
+            Color::White => Color::Black,
+            Color::Black => Color::White,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, EnumIter)]
+pub enum Token {
+    Daimyo,
+    Scout,
+    Hammer,
+    Hook,
+    Wave,
+    Hand,
+    Bomb,
+}
+
+/// The contents of every space, indexed by [`crate::bitboard`] cell, with a bitboard of the
+/// occupied spaces and of each player's tokens and the cell of every piece kept alongside.
 #[derive(Clone)]
 pub struct Board {
-    pub spaces: [[Space; 11]; 11],
+    spaces: [Space; CELLS],
+    occupied: Bitboard,
+    tokens: [Bitboard; 2],
+    locations: [u8; PIECES],  // NO_CELL for pieces off the board
+}
+
+impl Default for Board {
+    fn default() -> Self {
+        Board::new()
+    }
 }
 
 impl Board {
+    pub fn empty() -> Self {
+        Board {
+            spaces: [Space::Empty; CELLS],
+            occupied: Bitboard::EMPTY,
+            tokens: [Bitboard::EMPTY; 2],
+            locations: [NO_CELL; PIECES],
+        }
+    }
+
     pub fn new() -> Self {
-        let board = Board {
-            spaces: [[Space::Empty; 11]; 11],
-        };
-        // TODO place rocks and daimyos
+        let mut board = Board::empty();
+        board.set_space(CENTER_SPACE, Space::Occupied(Piece::BadRock));
+        board.set_space(Coordinates(-4, 0, 4), Space::Occupied(Piece::GoodRock));
+        board.set_space(Coordinates(4, 0, -4), Space::Occupied(Piece::GoodRock2));
+        board.set_space(Coordinates(-1, 2, -1), Space::Occupied(Piece::Token(Color::White, Token::Daimyo)));
+        board.set_space(Coordinates(1, -2, 1), Space::Occupied(Piece::Token(Color::Black, Token::Daimyo)));
         board
     }
-}
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
-pub enum Space {
-    Empty,
-    Occupied(Piece),
-}
+    /// The contents of a space, `Empty` off the board.
+    pub fn get_space(&self, coord: Coordinates) -> Space {
+        match bitboard::cell(coord) {
+            Some(cell) => self.spaces[cell],
+            None => Space::Empty,
+        }
+    }
+
+    pub fn set_space(&mut self, coord: Coordinates, space: Space) {
+        self.set_cell(bitboard::cell(coord).expect("Attempted to set a space off the board"), space);
+    }
 
+    pub fn cell(&self, cell: usize) -> Space {
+        self.spaces[cell]
+    }
+
+    pub fn set_cell(&mut self, cell: usize, space: Space) {
+        if let Space::Occupied(previous) = self.spaces[cell] {
+            if let Piece::Token(color, _) = previous {
+                self.tokens[color as usize].remove(cell);
+            }
+            // The piece may already have been placed on the space it is moving to
+            if self.locations[previous.index()] as usize == cell {
+                self.locations[previous.index()] = NO_CELL;
+            }
+        }
+        match space {
+            Space::Empty => self.occupied.remove(cell),
+            Space::Occupied(piece) => {
+                self.occupied.insert(cell);
+                if let Piece::Token(color, _) = piece {
+                    self.tokens[color as usize].insert(cell);
+                }
+                self.locations[piece.index()] = cell as u8;
+            }
+        }
+        self.spaces[cell] = space;
+    }
+
+    /// The cell of a piece, `None` when it is off the board.
+    pub fn locate(&self, piece: Piece) -> Option<usize> {
+        match self.locations[piece.index()] {
+            NO_CELL => None,
+            cell => Some(cell as usize),
+        }
+    }
+
+    pub fn occupied(&self) -> Bitboard {
+        self.occupied
+    }
+
+    /// The spaces holding tokens of `color`.
+    pub fn tokens(&self, color: Color) -> Bitboard {
+        self.tokens[color as usize]
+    }
+
+    pub fn move_to(&mut self, target: Coordinates, destination: Coordinates) {
+        self.set_space(destination, self.get_space(target));
+        self.set_space(target, Space::Empty);
+    }
+
+    pub fn move_piece(&mut self, piece: Piece, direction: Direction) {
+        let coordinates = self.find(piece).unwrap();
+        self.move_to(coordinates, coordinates + direction);
+    }
+
+    pub fn gate_coords(&self, color: Color) -> Coordinates {
+        match color {
+            Color::Black => Coordinates(2, -4, 2),
+            Color::White => Coordinates(-2, 4, -2),
+        }
+    }
+
+    pub fn gate_is_empty(&self, color: Color) -> bool {
+        let coordinates = self.gate_coords(color);
+        self.get_space(coordinates) == Space::Empty
+    }
+
+    pub fn deploy(&mut self, color: Color, token: Token) {
+        if !self.gate_is_empty(color) {
+            panic!("Attempted to deploy to occupied gate");
+        }
+        self.set_space(self.gate_coords(color), Space::Occupied(Piece::Token(color, token)));
+    }
+
+    pub fn find(&self, piece: Piece) -> Option<Coordinates> {
+        self.locate(piece).map(bitboard::coordinates)
+    }
+
+    pub fn find_all_of_color(&self, target_color: Color) -> impl Iterator<Item = (Token, Coordinates)> + '_ {
+        self.tokens[target_color as usize].cells().filter_map(move |cell| match self.spaces[cell] {
+            Space::Occupied(Piece::Token(_, token)) => Some((token, bitboard::coordinates(cell))),
+            _ => None,
+        })
+    }
+
+    pub fn is_in_village(&self, coordinates: Coordinates, color: Color) -> bool {
+        match color {
+            Color::Black => coordinates.1 <= -4,
+            Color::White => coordinates.1 >= 4,
+        }
+    }
+
+    pub fn is_empty(&self, coordinates: Coordinates) -> bool {
+        matches!(self.get_space(coordinates), Space::Empty)
+    }
+
+    pub fn is_empty_cell(&self, cell: usize) -> bool {
+        !self.occupied.contains(cell)
+    }
+
+    pub fn swap(&mut self, target: Coordinates, destination: Coordinates) {
+        let destination_contents = self.get_space(destination);
+        self.set_space(destination, self.get_space(target));
+        self.set_space(target, destination_contents);
+    }
+}
@@ -0,0 +1,26 @@
+use crate::board::Token;
+use crate::coordinates::{Coordinates, Direction};
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Choice {
+    #[default]
+    Pass,
+    Deploy(Token, Option<Direction>),
+    Move(Token, Direction),
+    UseAbility(Ability),
+    Revive(Token),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Ability {
+    Hammer { target: Coordinates, direction: Direction, distance: usize },
+    Wave { target: Coordinates, destination: Coordinates },
+    /// The Wave at `origin` reflects the line of pieces in `direction` (the `buffed-wave` rule).
+    BuffedWave { origin: Coordinates, direction: Direction },
+    Scout { target: Coordinates, destination: Coordinates },
+    /// `hand_direction` turns the Hand it teleports under the `daimyo-turns-hand` ruling.
+    Daimyo { target: Coordinates, destination: Coordinates, hand_direction: Option<Direction> },
+    Hook { target: Coordinates, direction: Direction, distance: usize },
+    Bomb { origin: Coordinates },
+    Hand { origin: Coordinates, move_direction: Direction, hand_direction: Direction }
+}
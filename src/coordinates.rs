@@ -0,0 +1,72 @@
+use std::ops;
+
+use strum_macros::EnumIter;
+
+pub const CENTER_SPACE: Coordinates = Coordinates(0, 0, 0);
+
+#[derive(Copy, Clone, Hash, Eq, PartialEq, Debug)]
+pub struct Coordinates(pub i8, pub i8, pub i8);
+
+impl Coordinates {
+    pub fn is_off_board(&self) -> bool {
+        self.0 < -5
+            || self.0 > 5
+            || self.1 < -5
+            || self.1 > 5
+            || self.2 < -5
+            || self.2 > 5
+    }
+
+    /// The number of steps between two spaces.
+    pub fn distance(&self, other: Coordinates) -> usize {
+        let steps = |a: i8, b: i8| (a - b).unsigned_abs() as usize;
+        steps(self.0, other.0).max(steps(self.1, other.1)).max(steps(self.2, other.2))
+    }
+}
+
+impl ops::Add<Coordinates> for Coordinates {
+    type Output = Coordinates;
+
+    fn add(self, rhs: Coordinates) -> Coordinates {
+        Coordinates(self.0 + rhs.0, self.1 + rhs.1, self.2 + rhs.2)
+    }
+}
+
+impl ops::Add<Direction> for Coordinates {
+    type Output = Coordinates;
+
+    fn add(self, rhs: Direction) -> Coordinates {
+        let Coordinates(q, r, s) = self;
+        match rhs {
+            Direction::Left => Coordinates(q - 1, r, s + 1),
+            Direction::UpLeft => Coordinates(q, r - 1, s + 1),
+            Direction::UpRight => Coordinates(q + 1, r - 1, s),
+            Direction::Right => Coordinates(q + 1, r, s - 1),
+            Direction::DownRight => Coordinates(q, r + 1, s - 1),
+            Direction::DownLeft => Coordinates(q - 1, r + 1, s),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, EnumIter)]
+pub enum Direction {
+    Left,
+    UpLeft,
+    UpRight,
+    Right,
+    DownRight,
+    DownLeft,
+}
+
+impl Direction {
+    pub fn opposite(&self) -> Direction  {
+        match self {
+            Direction::Left => Direction::Right,
+            Direction::UpLeft => Direction::DownRight,
+            Direction::UpRight => Direction::DownLeft,
+            Direction::Right => Direction::Left,
+            Direction::DownRight => Direction::UpLeft,
+            Direction::DownLeft => Direction::UpRight,
+        }
+    }
+}
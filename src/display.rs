@@ -0,0 +1,104 @@
+use std::fmt;
+
+use colored::Colorize;
+
+use crate::board::{Board, Color, Piece, Space, Token};
+use crate::coordinates::Coordinates;
+
+impl fmt::Display for Space {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Space::Empty => write!(f, "⚫"),
+            Space::Occupied(piece) => write!(f, "{}", piece),
+        }
+    }
+}
+
+impl fmt::Display for Piece {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Piece::BadRock => write!(f, "{}", String::from("🪨").on_red()),
+            Piece::GoodRock | Piece::GoodRock2 => write!(f, "🪨"),
+            Piece::Token(color, token) => {
+                match color {
+                    Color::White => write!(f, "{}", format!("{}", token).on_white()),
+                    Color::Black => write!(f, "{}", format!("{}", token).on_black()),
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Daimyo => write!(f, "🧙"),
+            Token::Scout => write!(f, "🥾"),
+            Token::Hammer => write!(f, "🔨"),
+            Token::Hook => write!(f, "🪝"),
+            Token::Wave => write!(f, "🌊"),
+            Token::Hand => write!(f, "🤏"),
+            Token::Bomb => write!(f, "💣"),
+        }
+    }
+}
+
+impl Board {
+    pub fn print(&self) {
+        println!("{}", self);
+    }
+}
+
+impl fmt::Display for Board {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        //for q in -5..=5 {
+        //    for r in -5..=5 {
+        //        let s = -q - r;
+        //        let coordinates = Coordinates(q, r, s);
+        //        match self.get_space(coordinates) {
+        //            Space::Occupied(piece) => {
+        //                println!("{:?} - {:?}", coordinates, piece);
+        //            }
+        //            Space::Empty => {}
+        //        }
+        //    }
+        //}
+        write!(
+            f,
+"
+          {0}  {1}  {2}  {3}  {4}  {5}
+        {6}  {7}  {8}  {9}  {10}  {11}  {12}
+      {13}  {14}  {15}  {16}  {17}  {18}  {19}  {20}
+    {21}  {22}  {23}  {24}  {25}  {26}  {27}  {28}  {29}
+  {30}  {31}  {32}  {33}  {34}  {35}  {36}  {37}  {38}  {39}
+{40}  {41}  {42}  {43}  {44}  {45}  {46}  {47}  {48}  {49}  {50}
+  {51}  {52}  {53}  {54}  {55}  {56}  {57}  {58}  {59}  {60}
+    {61}  {62}  {63}  {64}  {65}  {66}  {67}  {68}  {69}
+      {70}  {71}  {72}  {73}  {74}  {75}  {76}  {77}
+        {78}  {79}  {80}  {81}  {82}  {83}  {84}
+          {85}  {86}  {87}  {88}  {89}  {90}
+",
+self.get_space(Coordinates(0, -5, 5)), self.get_space(Coordinates(1, -5, 4)), self.get_space(Coordinates(2, -5, 3)), self.get_space(Coordinates(3, -5, 2)), self.get_space(Coordinates(4, -5, 1)), self.get_space(Coordinates(5, -5, 0)),
+
+self.get_space(Coordinates(-1, -4, 5)), self.get_space(Coordinates(0, -4, 4)), self.get_space(Coordinates(1, -4, 3)), self.get_space(Coordinates(2, -4, 2)), self.get_space(Coordinates(3, -4, 1)), self.get_space(Coordinates(4, -4, 0)), self.get_space(Coordinates(5, -4, -1)),
+
+self.get_space(Coordinates(-2, -3, 5)), self.get_space(Coordinates(-1, -3, 4)), self.get_space(Coordinates(0, -3, 3)), self.get_space(Coordinates(1, -3, 2)), self.get_space(Coordinates(2, -3, 1)), self.get_space(Coordinates(3, -3, 0)), self.get_space(Coordinates(4, -3, -1)), self.get_space(Coordinates(5, -3, -2)),
+
+self.get_space(Coordinates(-3, -2, 5)), self.get_space(Coordinates(-2, -2, 4)), self.get_space(Coordinates(-1, -2, 3)), self.get_space(Coordinates(0, -2, 2)), self.get_space(Coordinates(1, -2, 1)), self.get_space(Coordinates(2, -2, 0)), self.get_space(Coordinates(3, -2, -1)), self.get_space(Coordinates(4, -2, -2)), self.get_space(Coordinates(5, -2, -3)),
+
+self.get_space(Coordinates(-4, -1, 5)), self.get_space(Coordinates(-3, -1, 4)), self.get_space(Coordinates(-2, -1, 3)), self.get_space(Coordinates(-1, -1, 2)), self.get_space(Coordinates(0, -1, 1)), self.get_space(Coordinates(1, -1, 0)), self.get_space(Coordinates(2, -1, -1)), self.get_space(Coordinates(3, -1, -2)), self.get_space(Coordinates(4, -1, -3)), self.get_space(Coordinates(5, -1, -4)),
+
+self.get_space(Coordinates(-5, 0, 5)), self.get_space(Coordinates(-4, 0, 4)), self.get_space(Coordinates(-3, 0, 3)), self.get_space(Coordinates(-2, 0, 2)), self.get_space(Coordinates(-1, 0, 1)), self.get_space(Coordinates(0, 0, 0)), self.get_space(Coordinates(1, 0, -1)), self.get_space(Coordinates(2, 0, -2)), self.get_space(Coordinates(3, 0, -3)), self.get_space(Coordinates(4, 0, -4)), self.get_space(Coordinates(5, 0, -5)),
+
+self.get_space(Coordinates(-5, 1, 4)), self.get_space(Coordinates(-4, 1, 3)), self.get_space(Coordinates(-3, 1, 2)), self.get_space(Coordinates(-2, 1, 1)), self.get_space(Coordinates(-1, 1, 0)), self.get_space(Coordinates(0, 1, -1)), self.get_space(Coordinates(1, 1, -2)), self.get_space(Coordinates(2, 1, -3)), self.get_space(Coordinates(3, 1, -4)), self.get_space(Coordinates(4, 1, -5)),
+
+self.get_space(Coordinates(-5, 2, 3)), self.get_space(Coordinates(-4, 2, 2)), self.get_space(Coordinates(-3, 2, 1)), self.get_space(Coordinates(-2, 2, 0)), self.get_space(Coordinates(-1, 2, -1)), self.get_space(Coordinates(0, 2, -2)), self.get_space(Coordinates(1, 2, -3)), self.get_space(Coordinates(2, 2, -4)), self.get_space(Coordinates(3, 2, -5)),
+
+self.get_space(Coordinates(-5, 3, 2)), self.get_space(Coordinates(-4, 3, 1)), self.get_space(Coordinates(-3, 3, 0)), self.get_space(Coordinates(-2, 3, -1)), self.get_space(Coordinates(-1, 3, -2)), self.get_space(Coordinates(0, 3, -3)), self.get_space(Coordinates(1, 3, -4)), self.get_space(Coordinates(2, 3, -5)),
+
+self.get_space(Coordinates(-5, 4, 1)), self.get_space(Coordinates(-4, 4, 0)), self.get_space(Coordinates(-3, 4, -1)), self.get_space(Coordinates(-2, 4, -2)), self.get_space(Coordinates(-1, 4, -3)), self.get_space(Coordinates(0, 4, -4)), self.get_space(Coordinates(1, 4, -5)),
+
+self.get_space(Coordinates(-5, 5, 0)), self.get_space(Coordinates(-4, 5, -1)), self.get_space(Coordinates(-3, 5, -2)), self.get_space(Coordinates(-2, 5, -3)), self.get_space(Coordinates(-1, 5, -4)), self.get_space(Coordinates(0, 5, -5)),
+)
+    }
+}
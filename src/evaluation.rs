@@ -0,0 +1,94 @@
+//! Static evaluation of positions for agents and searches that cannot look to the end.
+//!
+//! The evaluation is a logistic model over a few features of the position, each the
+//! difference between the player and the opponent. The weights are set by hand: a step of the
+//! bad rock toward a village counts for most, then a threatened Daimyo and then material.
+
+use crate::board::{Color, Piece, Token};
+use crate::coordinates::{Coordinates, CENTER_SPACE};
+use crate::rules::{Game, TurnState};
+
+/// Spaces on the edge are this far from the center.
+const EDGE_DISTANCE: usize = 5;
+
+/// The features of a position for one player.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub(crate) struct Features {
+    /// How much closer the bad rock is to the opponent's village than to the player's.
+    pub(crate) bad_rock: f64,
+    /// How much closer the good rocks are to the player's village than to the opponent's.
+    pub(crate) good_rocks: f64,
+    /// Tokens on the board or in the supply, less the opponent's.
+    pub(crate) material: f64,
+    /// Whether the opponent's Daimyo is dead or on the edge, less the same for the player's.
+    pub(crate) daimyo: f64,
+    /// Opponent's tokens on the edge, less the player's.
+    pub(crate) edge: f64,
+}
+
+/// The weights of the features the evaluation uses, in the order of `Features::evaluated`.
+const WEIGHTS: [f64; 5] = [0.23, 0.03, 0.06, 0.13, 0.03];
+
+impl Features {
+    /// The bad rock, good rocks, material, Daimyo and edge features.
+    fn evaluated(&self) -> [f64; 5] {
+        [self.bad_rock, self.good_rocks, self.material, self.daimyo, self.edge]
+    }
+}
+
+/// Steps from `coordinates` to the nearest space of the village of `color`.
+fn distance_to_village(coordinates: Coordinates, color: Color) -> f64 {
+    let steps = match color {
+        Color::White => 4 - coordinates.1,
+        Color::Black => coordinates.1 + 4,
+    };
+    steps.max(0) as f64
+}
+
+impl Game {
+    fn alive_tokens(&self, color: Color) -> f64 {
+        (self.board.tokens(color).count() as usize + self.supplies[color as usize].len()) as f64
+    }
+
+    fn tokens_on_edge(&self, color: Color) -> f64 {
+        self.board.find_all_of_color(color).filter(|&(_, coordinates)| coordinates.distance(CENTER_SPACE) == EDGE_DISTANCE).count() as f64
+    }
+
+    /// 1 if the Daimyo of `color` is dead, 0.5 on the edge and 0 otherwise.
+    fn daimyo_danger(&self, color: Color) -> f64 {
+        match self.board.find(Piece::Token(color, Token::Daimyo)) {
+            Some(coordinates) if coordinates.distance(CENTER_SPACE) == EDGE_DISTANCE => 0.5,
+            Some(_) => 0.0,
+            None => 1.0,
+        }
+    }
+
+    pub(crate) fn features(&self, color: Color) -> Features {
+        let opponent = color.opposite();
+        let mut features = Features::default();
+        if let Some(bad_rock) = self.board.find(Piece::BadRock) {
+            features.bad_rock = distance_to_village(bad_rock, color) - distance_to_village(bad_rock, opponent);
+        }
+        for good_rock in [Piece::GoodRock, Piece::GoodRock2].into_iter().filter_map(|rock| self.board.find(rock)) {
+            features.good_rocks += distance_to_village(good_rock, opponent) - distance_to_village(good_rock, color);
+        }
+        features.material = self.alive_tokens(color) - self.alive_tokens(opponent);
+        features.daimyo = self.daimyo_danger(opponent) - self.daimyo_danger(color);
+        features.edge = self.tokens_on_edge(opponent) - self.tokens_on_edge(color);
+        features
+    }
+
+    /// The chance that `color` wins from this position, between 0 and 1. Finished games score
+    /// 1 for a win, 0 for a loss and 0.5 for a draw.
+    pub fn evaluate(&self, color: Color) -> f64 {
+        if let TurnState::Over(outcome) = self.turn_state {
+            return match outcome.winner {
+                Some(winner) if winner == color => 1.0,
+                Some(_) => 0.0,
+                None => 0.5,
+            };
+        }
+        let score: f64 = WEIGHTS.iter().zip(self.features(color).evaluated()).map(|(weight, feature)| weight * feature).sum();
+        1.0 / (1.0 + (-score).exp())
+    }
+}
@@ -0,0 +1,70 @@
+//! What happened while a choice was applied, for renderers, statistics and logs.
+//!
+//! `Game::apply_choice_with_events` applies a choice like `apply_choice` and returns the events
+//! in the order they happened. Events are only collected on request, so search pays nothing.
+
+use crate::mcts::Game as MctsGame;
+
+use crate::board::{Color, Piece, Token};
+use crate::choices::Choice;
+use crate::coordinates::{Coordinates, Direction};
+use crate::outcome::GameOutcome;
+use crate::rules::Game;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Event {
+    Deployed { color: Color, token: Token, to: Coordinates },
+    /// A piece moved without being pushed: a basic move, the Wave, the Daimyo's teleport or the
+    /// Hand's move, including the piece it carries.
+    Moved { piece: Piece, from: Coordinates, to: Coordinates },
+    /// The Scout's swap: `piece` went from `from` to `to` and `with` the other way.
+    Swapped { piece: Piece, with: Piece, from: Coordinates, to: Coordinates },
+    /// A piece slid `distance` spaces from a Hammer, Hook or Bomb push.
+    Pushed { piece: Piece, from: Coordinates, to: Coordinates, distance: usize },
+    KilledOffEdge { color: Color, token: Token, from: Coordinates },
+    KilledInPit { color: Color, token: Token },
+    Revived { color: Color, token: Token },
+    HandRotated { color: Color, from: Direction, to: Direction },
+    /// A rock arrived in the village of `village`.
+    RockEnteredVillage { rock: Piece, village: Color },
+    GameEnded(GameOutcome),
+}
+
+const ROCKS: [Piece; 3] = [Piece::GoodRock, Piece::GoodRock2, Piece::BadRock];
+
+impl Game {
+    /// Applies `choice` and returns everything that happened.
+    pub fn apply_choice_with_events(&mut self, choice: &Choice) -> Vec<Event> {
+        let logging = self.logging;
+        self.logging = true;
+        let villages_before = self.rock_villages();
+        self.apply_choice(choice);
+        let villages_after = self.rock_villages();
+        // Rocks arrive before the game ends, even though arrivals are only known afterwards
+        let mut position = self.events.iter().position(|event| matches!(event, Event::GameEnded(_))).unwrap_or(self.events.len());
+        for ((rock, before), after) in ROCKS.into_iter().zip(villages_before).zip(villages_after) {
+            if let Some(village) = after {
+                if before != after {
+                    self.events.insert(position, Event::RockEnteredVillage { rock, village });
+                    position += 1;
+                }
+            }
+        }
+        self.logging = logging;
+        std::mem::take(&mut self.events)
+    }
+
+    pub(crate) fn emit(&mut self, event: Event) {
+        if self.logging {
+            self.events.push(event);
+        }
+    }
+
+    /// The village each rock is in, in the order of `ROCKS`.
+    fn rock_villages(&self) -> [Option<Color>; 3] {
+        ROCKS.map(|rock| {
+            let coordinates = self.board.find(rock)?;
+            [Color::White, Color::Black].into_iter().find(|&color| self.board.is_in_village(coordinates, color))
+        })
+    }
+}
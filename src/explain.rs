@@ -0,0 +1,180 @@
+//! Plain English explanations of choices, for logs, interactive play and record comments,
+//! e.g. "White Hook pulls Black Scout two spaces toward it; Black Scout falls into the pit".
+
+use crate::board::{Piece, Space, Token};
+use crate::choices::{Ability, Choice};
+use crate::coordinates::Direction;
+use crate::events::Event;
+use crate::rules::Game;
+
+fn piece_name(piece: Piece) -> String {
+    match piece {
+        Piece::GoodRock | Piece::GoodRock2 => String::from("a good rock"),
+        Piece::BadRock => String::from("the bad rock"),
+        Piece::Token(color, token) => format!("{:?} {:?}", color, token),
+    }
+}
+
+fn direction_name(direction: Direction) -> &'static str {
+    match direction {
+        Direction::Left => "left",
+        Direction::UpLeft => "up-left",
+        Direction::UpRight => "up-right",
+        Direction::Right => "right",
+        Direction::DownRight => "down-right",
+        Direction::DownLeft => "down-left",
+    }
+}
+
+fn spaces(count: usize) -> String {
+    let number = match count {
+        1 => return String::from("one space"),
+        2 => "two",
+        3 => "three",
+        4 => "four",
+        _ => return format!("{} spaces", count),
+    };
+    format!("{} spaces", number)
+}
+
+/// The clause for an event the main clause of the choice did not already describe, if it is
+/// worth telling.
+fn consequence(event: &Event) -> Option<String> {
+    match *event {
+        Event::Pushed { piece, to, distance, .. } => Some(format!("{} is pushed {} to {}", piece_name(piece), spaces(distance), to)),
+        Event::KilledOffEdge { color, token, .. } => Some(format!("{:?} {:?} falls off the board", color, token)),
+        Event::KilledInPit { color, token } => Some(format!("{:?} {:?} falls into the pit", color, token)),
+        Event::RockEnteredVillage { rock, village } => Some(format!("{} enters {:?}'s village", piece_name(rock), village)),
+        Event::GameEnded(outcome) => Some(match outcome.winner {
+            Some(winner) => format!("{:?} wins by {}", winner, outcome.reason),
+            None => format!("the game is drawn by {}", outcome.reason),
+        }),
+        _ => None,
+    }
+}
+
+impl Game {
+    /// Explains `choice` as played from this state, given the events it produced.
+    pub fn explain(&self, choice: &Choice, events: &[Event]) -> String {
+        let color = match self.turn_state.get_color() {
+            Some(color) => color,
+            None => return String::from("The game is over"),
+        };
+        let piece_at = |coordinates| match self.board.get_space(coordinates) {
+            Space::Occupied(piece) => piece_name(piece),
+            Space::Empty => String::from("nothing"),
+        };
+        let pushed = events.iter().find_map(|event| match *event {
+            Event::Pushed { distance, .. } => Some(distance),
+            _ => None,
+        });
+        let fell_off = events.iter().any(|event| matches!(event, Event::KilledOffEdge { .. }));
+        // Events the main clause already tells
+        let mut told: Vec<Event> = vec![];
+
+        let main = match *choice {
+            Choice::Pass => format!("{:?} passes", color),
+            Choice::Deploy(token, direction) => {
+                let facing = direction.map(|direction| format!(" facing {}", direction_name(direction))).unwrap_or_default();
+                format!("{:?} deploys the {:?} at {}{}", color, token, self.board.gate_coords(color), facing)
+            }
+            Choice::Move(token, direction) => match self.board.find(Piece::Token(color, token)) {
+                Some(from) if (from + direction).is_off_board() => {
+                    told.extend(events.iter().filter(|event| matches!(event, Event::KilledOffEdge { .. })));
+                    format!("{:?} {:?} steps off the board", color, token)
+                }
+                Some(from) => format!("{:?} {:?} moves {} to {}", color, token, direction_name(direction), from + direction),
+                None => format!("{:?} {:?} moves {}", color, token, direction_name(direction)),
+            },
+            Choice::Revive(token) => format!("{:?} revives the {:?}, returning it to the supply", color, token),
+            Choice::UseAbility(ability) => {
+                let ability_events = |event: &&Event| matches!(event, Event::Pushed { .. } | Event::KilledOffEdge { .. });
+                match ability {
+                    Ability::Hammer { target, direction, distance } => {
+                        told.extend(events.iter().filter(ability_events));
+                        match (pushed, fell_off) {
+                            (_, true) => format!("{:?} Hammer pushes {} off the board", color, piece_at(target)),
+                            (Some(moved), false) => format!(
+                                "{:?} Hammer pushes {} {} {}{}",
+                                color,
+                                piece_at(target),
+                                spaces(moved),
+                                direction_name(direction),
+                                if moved < distance { ", where it is blocked" } else { "" },
+                            ),
+                            (None, false) => format!("{:?} Hammer hits {}, but it is blocked", color, piece_at(target)),
+                        }
+                    }
+                    Ability::Hook { target, distance, .. } => {
+                        told.extend(events.iter().filter(ability_events));
+                        match pushed {
+                            Some(moved) => format!(
+                                "{:?} Hook pulls {} {} toward it{}",
+                                color,
+                                piece_at(target),
+                                spaces(moved),
+                                if moved < distance { ", where it is blocked" } else { "" },
+                            ),
+                            None => format!("{:?} Hook hooks {}, but it is blocked", color, piece_at(target)),
+                        }
+                    }
+                    Ability::Wave { target, destination } => {
+                        format!("{:?} Wave flips {} over it to {}", color, piece_at(target), destination)
+                    }
+                    Ability::BuffedWave { origin, direction } => {
+                        let line = self.wave_line(origin, direction).unwrap_or_default();
+                        let names: Vec<String> = line.iter().map(|&(target, _)| piece_at(target)).collect();
+                        format!("{:?} Wave flips {} over it", color, names.join(", "))
+                    }
+                    Ability::Scout { target, destination } => {
+                        format!("{:?} Scout at {} swaps places with {} at {}", color, target, piece_at(destination), destination)
+                    }
+                    Ability::Daimyo { target, destination, hand_direction } => {
+                        let teleported = match self.board.get_space(target) {
+                            Space::Occupied(Piece::Token(_, Token::Daimyo)) => String::from("itself"),
+                            Space::Occupied(Piece::Token(_, token)) => format!("the {:?}", token),
+                            _ => piece_at(target),
+                        };
+                        let facing = hand_direction
+                            .map(|direction| format!(", facing {}", direction_name(direction)))
+                            .unwrap_or_default();
+                        format!("{:?} Daimyo teleports {} from {} to {}{}", color, teleported, target, destination, facing)
+                    }
+                    Ability::Bomb { origin } => format!("{:?} Bomb explodes at {}", color, origin),
+                    Ability::Hand { origin, move_direction, hand_direction } => {
+                        let carried = events.iter().find_map(|event| match *event {
+                            Event::Moved { piece, .. } if piece != Piece::Token(color, Token::Hand) => Some(piece),
+                            _ => None,
+                        });
+                        let carrying = carried.map(|piece| format!(", carrying {}", piece_name(piece))).unwrap_or_default();
+                        let turn = if hand_direction == self.hand_directions[color as usize] {
+                            format!("still facing {}", direction_name(hand_direction))
+                        }
+                        else {
+                            format!("turning to face {}", direction_name(hand_direction))
+                        };
+                        format!(
+                            "{:?} Hand moves {} to {}{}, {}",
+                            color,
+                            direction_name(move_direction),
+                            origin + move_direction,
+                            carrying,
+                            turn,
+                        )
+                    }
+                }
+            }
+        };
+
+        let mut clauses = vec![main];
+        clauses.extend(events.iter().filter(|event| !told.contains(event)).filter_map(consequence));
+        clauses.join("; ")
+    }
+
+    /// Explains `choice` as played from this state, working out its events on a copy.
+    pub fn explain_choice(&self, choice: &Choice) -> String {
+        let mut after = self.clone();
+        let events = after.apply_choice_with_events(choice);
+        self.explain(choice, &events)
+    }
+}
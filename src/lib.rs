@@ -0,0 +1,42 @@
+extern crate colored;
+extern crate strum;
+extern crate strum_macros;
+
+pub mod agent;
+pub mod bitboard;
+pub mod board;
+pub mod choices;
+pub mod coordinates;
+pub mod display;
+pub mod evaluation;
+pub mod events;
+pub mod explain;
+pub mod mcts;
+pub mod notation;
+pub mod outcome;
+pub mod perft;
+pub mod position;
+pub mod record;
+pub mod rollout;
+pub mod rules;
+pub mod rulings;
+pub mod search;
+pub mod validation;
+pub mod zobrist;
+
+pub use agent::{Agent, Decision, GreedyAgent, HumanAgent, LineInput, MctsAgent, RandomAgent, SharedStdin};
+pub use board::{Board, Color, Piece, Space, Token};
+pub use choices::{Ability, Choice};
+pub use coordinates::{Coordinates, Direction, CENTER_SPACE};
+pub use events::Event;
+pub use mcts::{ChildStats, Mcts, MctsSettings, Policy, SearchResult, Uniform};
+pub use notation::NotationError;
+pub use outcome::{GameOutcome, OutcomeReason};
+pub use perft::{divide, perft, perft_counts, PerftCounts};
+pub use position::PositionError;
+pub use record::{GameRecord, GameResult};
+pub use rollout::RolloutWeights;
+pub use rules::{Game, RuleSet, TurnState, Undo};
+pub use rulings::{HandDeployOrientation, HandGrab, Rulings};
+pub use search::{SearchBudget, Searcher};
+pub use validation::IllegalMove;
@@ -0,0 +1,266 @@
+//! Monte Carlo tree search with UCT.
+//!
+//! The tree is kept between searches: when the next search starts from a position already in
+//! the tree, e.g. after the player's own choice and the opponent's reply, its subtree becomes
+//! the new root and keeps its statistics.
+
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::time::Instant;
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+/// How many choices after the last root the search looks for the new one.
+const MAX_REUSE_DEPTH: usize = 8;
+
+/// A game the search can play. Players may act several times in a row.
+pub trait Game: Clone {
+    type Choice: Copy + Clone + Eq + Hash + Default + Debug;
+    type PlayerId: Copy + Clone + Eq + Debug;
+
+    fn get_all_choices(&self) -> Vec<Self::Choice>;
+    fn apply_choice(&mut self, choice: &Self::Choice);
+    fn get_active_player_id(&self) -> Self::PlayerId;
+    fn is_terminal(&self) -> bool;
+    /// The reward of `player` for this state, between 0 and 1. Playouts cut short by
+    /// `MctsSettings::playout_depth` end in states that are not terminal, which should be
+    /// rewarded with an estimate of the chance to win.
+    fn reward_for(&self, player: Self::PlayerId) -> f64;
+    /// Identifies the state, to find it again in the tree. States with the same key must have
+    /// the same future, including any draw by a limit or a repetition.
+    fn position_key(&self) -> u64;
+}
+
+/// Picks the choices of playouts.
+pub trait Policy<G: Game> {
+    /// Picks one of `choices`, the choices of `game`. `game` must be left as it was.
+    fn choose(&self, game: &mut G, choices: &[G::Choice], rng: &mut StdRng) -> G::Choice;
+}
+
+/// Plays uniformly at random.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct Uniform;
+
+impl<G: Game> Policy<G> for Uniform {
+    fn choose(&self, _: &mut G, choices: &[G::Choice], rng: &mut StdRng) -> G::Choice {
+        choices[rng.gen_range(0..choices.len())]
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct MctsSettings {
+    /// The weight of exploration against the average reward in UCT.
+    pub exploration: f64,
+    pub seed: u64,
+    /// Whether to keep the subtree of the new position between searches.
+    pub reuse_tree: bool,
+    /// The most choices a playout plays before stopping, `None` to play to the end.
+    pub playout_depth: Option<usize>,
+}
+
+/// The default playout depth was 50 with uniform playouts. Playouts guided by
+/// `RolloutWeights` cost more per choice and reach a telling position sooner, so the default
+/// stops them at 25, a tuning choice that `--playout-depth` overrides.
+impl Default for MctsSettings {
+    fn default() -> Self {
+        MctsSettings { exploration: std::f64::consts::SQRT_2, seed: 0, reuse_tree: true, playout_depth: Some(25) }
+    }
+}
+
+/// The statistics of a choice at the root after a search.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ChildStats<C> {
+    pub choice: C,
+    pub visits: u32,
+    /// The average reward of the player making the choice.
+    pub value: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchResult<C> {
+    /// The most visited choice.
+    pub choice: C,
+    pub value: f64,
+    /// The iterations of this search, not counting those kept from earlier searches.
+    pub iterations: usize,
+    /// Every choice expanded at the root, most visited first.
+    pub children: Vec<ChildStats<C>>,
+}
+
+struct Node<G: Game> {
+    /// The choice leading here and the player who made it, `None` at the root.
+    choice: Option<(G::Choice, G::PlayerId)>,
+    key: u64,
+    visits: u32,
+    /// The total reward of the player who made `choice`.
+    reward: f64,
+    children: Vec<usize>,
+    /// Choices not expanded yet, `None` until the node is first selected.
+    untried: Option<Vec<G::Choice>>,
+}
+
+impl<G: Game> Node<G> {
+    fn new(choice: Option<(G::Choice, G::PlayerId)>, key: u64) -> Self {
+        Node { choice, key, visits: 0, reward: 0.0, children: vec![], untried: None }
+    }
+
+    fn value(&self) -> f64 {
+        if self.visits == 0 { 0.0 } else { self.reward / self.visits as f64 }
+    }
+}
+
+pub struct Mcts<G: Game, P: Policy<G> = Uniform> {
+    pub settings: MctsSettings,
+    pub policy: P,
+    rng: StdRng,
+    /// The tree, with the root first.
+    nodes: Vec<Node<G>>,
+}
+
+impl<G: Game> Mcts<G> {
+    pub fn new(settings: MctsSettings) -> Self {
+        Mcts::with_policy(settings, Uniform)
+    }
+}
+
+impl<G: Game, P: Policy<G>> Mcts<G, P> {
+    pub fn with_policy(settings: MctsSettings, policy: P) -> Self {
+        Mcts { settings, policy, rng: StdRng::seed_from_u64(settings.seed), nodes: vec![] }
+    }
+
+    /// Searches `game` for up to `max_iterations` iterations or until `deadline`, whichever
+    /// comes first. At least one iteration is always run.
+    ///
+    /// Panics if `game` is over, since there is no choice to find.
+    pub fn search(&mut self, game: &G, max_iterations: usize, deadline: Option<Instant>) -> SearchResult<G::Choice> {
+        assert!(!game.is_terminal(), "Cannot search a game that is over");
+        self.set_root(game);
+        let mut iterations = 0;
+        loop {
+            self.iterate(game);
+            iterations += 1;
+            if iterations >= max_iterations || deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+                break;
+            }
+        }
+
+        let mut children: Vec<ChildStats<G::Choice>> = self.nodes[0]
+            .children
+            .iter()
+            .map(|&child| {
+                let node = &self.nodes[child];
+                ChildStats { choice: node.choice.unwrap().0, visits: node.visits, value: node.value() }
+            })
+            .collect();
+        children.sort_by_key(|child| std::cmp::Reverse(child.visits));
+        let best = children[0];
+        SearchResult { choice: best.choice, value: best.value, iterations, children }
+    }
+
+    /// Makes the node of `game` the root, keeping its subtree, or starts a new tree.
+    fn set_root(&mut self, game: &G) {
+        let key = game.position_key();
+        let found = match self.nodes.first() {
+            Some(_) if self.settings.reuse_tree => self.find(key),
+            _ => None,
+        };
+        match found {
+            Some(0) => {}
+            Some(root) => {
+                let mut nodes = vec![];
+                self.copy_subtree(root, &mut nodes);
+                nodes[0].choice = None;
+                self.nodes = nodes;
+            }
+            None => self.nodes = vec![Node::new(None, key)],
+        }
+    }
+
+    /// The node with `key` nearest below the root.
+    fn find(&self, key: u64) -> Option<usize> {
+        let mut level = vec![0];
+        for _ in 0..=MAX_REUSE_DEPTH {
+            if let Some(&found) = level.iter().find(|&&node| self.nodes[node].key == key) {
+                return Some(found);
+            }
+            level = level.iter().flat_map(|&node| self.nodes[node].children.iter().copied()).collect();
+        }
+        None
+    }
+
+    /// Moves the subtree of `node` to the end of `nodes`, returning its new index.
+    fn copy_subtree(&mut self, node: usize, nodes: &mut Vec<Node<G>>) -> usize {
+        let index = nodes.len();
+        let children = std::mem::take(&mut self.nodes[node].children);
+        let untried = self.nodes[node].untried.take();
+        let old = &self.nodes[node];
+        nodes.push(Node { choice: old.choice, key: old.key, visits: old.visits, reward: old.reward, children: vec![], untried });
+        let children = children.into_iter().map(|child| self.copy_subtree(child, nodes)).collect();
+        nodes[index].children = children;
+        index
+    }
+
+    /// The child of `node` with the best upper confidence bound.
+    fn select(&self, node: usize) -> usize {
+        let log_visits = (self.nodes[node].visits.max(1) as f64).ln();
+        let bound = |child: usize| {
+            let child = &self.nodes[child];
+            child.value() + self.settings.exploration * (log_visits / child.visits as f64).sqrt()
+        };
+        *self.nodes[node]
+            .children
+            .iter()
+            .max_by(|&&a, &&b| bound(a).total_cmp(&bound(b)))
+            .unwrap()
+    }
+
+    fn iterate(&mut self, root: &G) {
+        let mut state = root.clone();
+        let mut path = vec![0];
+        let mut node = 0;
+
+        // Selection and expansion
+        while !state.is_terminal() {
+            if self.nodes[node].untried.is_none() {
+                let mut choices = state.get_all_choices();
+                choices.shuffle(&mut self.rng);
+                self.nodes[node].untried = Some(choices);
+            }
+            let player = state.get_active_player_id();
+            match self.nodes[node].untried.as_mut().unwrap().pop() {
+                Some(choice) => {
+                    state.apply_choice(&choice);
+                    let child = self.nodes.len();
+                    self.nodes.push(Node::new(Some((choice, player)), state.position_key()));
+                    self.nodes[node].children.push(child);
+                    path.push(child);
+                    break;
+                }
+                None => {
+                    node = self.select(node);
+                    state.apply_choice(&self.nodes[node].choice.unwrap().0);
+                    path.push(node);
+                }
+            }
+        }
+
+        // Playout
+        let mut depth = 0;
+        while !state.is_terminal() && self.settings.playout_depth.is_none_or(|playout_depth| depth < playout_depth) {
+            let choices = state.get_all_choices();
+            let choice = self.policy.choose(&mut state, &choices, &mut self.rng);
+            state.apply_choice(&choice);
+            depth += 1;
+        }
+
+        for node in path {
+            let node = &mut self.nodes[node];
+            node.visits += 1;
+            if let Some((_, player)) = node.choice {
+                node.reward += state.reward_for(player);
+            }
+        }
+    }
+}
@@ -0,0 +1,292 @@
+//! Text notation for choices.
+//!
+//! Spaces are named by a file letter and a rank number. The file runs from `a` to `k` along
+//! the `q` axis and the rank runs from `1` (Black's village) to `11` (White's village) along
+//! the `r` axis, so the center space is `f6`, White's gate is `d10` and Black's gate is `h2`.
+//!
+//! Tokens are written with a single letter:
+//!
+//! | Token  | Letter |
+//! |--------|--------|
+//! | Daimyo | `D`    |
+//! | Scout  | `S`    |
+//! | Hammer | `M`    |
+//! | Hook   | `K`    |
+//! | Wave   | `W`    |
+//! | Hand   | `H`    |
+//! | Bomb   | `B`    |
+//!
+//! Directions are `L`, `UL`, `UR`, `R`, `DR` and `DL`.
+//!
+//! | Choice                                                | Notation     |
+//! |-------------------------------------------------------|--------------|
+//! | `Pass`                                                | `pass`       |
+//! | `Deploy(Scout, None)`                                 | `+S`         |
+//! | `Deploy(Hand, Some(DownLeft))`                        | `+H/DL`      |
+//! | `Move(Scout, UpLeft)`                                 | `S>UL`       |
+//! | `Revive(Bomb)`                                        | `^B`         |
+//! | `Hammer { target: f6, direction: UpRight, distance: 3 }` | `M:f6>UR3` |
+//! | `Hook { target: f8, direction: UpLeft, distance: 2 }` | `K:f8>UL2`   |
+//! | `Wave { target: f5, destination: f7 }`                | `W:f5-f7`    |
+//! | `BuffedWave { origin: f6, direction: UpLeft }`        | `W:f6>UL`    |
+//! | `Scout { target: f6, destination: f8 }`               | `S:f6-f8`    |
+//! | `Daimyo { target: d10, destination: f7, hand_direction: None }` | `D:d10-f7` |
+//! | `Daimyo { target: d10, destination: f7, hand_direction: Some(Left) }` | `D:d10-f7/L` |
+//! | `Bomb { origin: f6 }`                                 | `B:f6`       |
+//! | `Hand { origin: f6, move_direction: UpRight, hand_direction: DownLeft }` | `H:f6>UR/DL` |
+//!
+//! Formatting a choice with `Display` and parsing the result with `FromStr` gives back the
+//! same choice for every choice whose coordinates are on the board.
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::board::Token;
+use crate::choices::{Ability, Choice};
+use crate::coordinates::{Coordinates, Direction};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NotationError {
+    UnknownToken(String),
+    UnknownDirection(String),
+    InvalidCoordinates(String),
+    InvalidDistance(String),
+    Malformed(String),
+}
+
+impl fmt::Display for NotationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NotationError::UnknownToken(s) => write!(f, "Unknown token '{}'", s),
+            NotationError::UnknownDirection(s) => write!(f, "Unknown direction '{}'", s),
+            NotationError::InvalidCoordinates(s) => write!(f, "'{}' is not a space on the board", s),
+            NotationError::InvalidDistance(s) => write!(f, "Invalid distance '{}'", s),
+            NotationError::Malformed(s) => write!(f, "Could not read '{}' as a choice", s),
+        }
+    }
+}
+
+impl Error for NotationError {}
+
+impl Token {
+    pub fn letter(&self) -> char {
+        match self {
+            Token::Daimyo => 'D',
+            Token::Scout => 'S',
+            Token::Hammer => 'M',
+            Token::Hook => 'K',
+            Token::Wave => 'W',
+            Token::Hand => 'H',
+            Token::Bomb => 'B',
+        }
+    }
+
+    pub fn from_letter(letter: char) -> Option<Token> {
+        match letter {
+            'D' => Some(Token::Daimyo),
+            'S' => Some(Token::Scout),
+            'M' => Some(Token::Hammer),
+            'K' => Some(Token::Hook),
+            'W' => Some(Token::Wave),
+            'H' => Some(Token::Hand),
+            'B' => Some(Token::Bomb),
+            _ => None,
+        }
+    }
+}
+
+fn parse_token(s: &str) -> Result<Token, NotationError> {
+    let mut chars = s.chars();
+    match (chars.next(), chars.next()) {
+        (Some(letter), None) => Token::from_letter(letter).ok_or_else(|| NotationError::UnknownToken(s.to_string())),
+        _ => Err(NotationError::UnknownToken(s.to_string())),
+    }
+}
+
+impl Direction {
+    pub fn abbreviation(&self) -> &'static str {
+        match self {
+            Direction::Left => "L",
+            Direction::UpLeft => "UL",
+            Direction::UpRight => "UR",
+            Direction::Right => "R",
+            Direction::DownRight => "DR",
+            Direction::DownLeft => "DL",
+        }
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.abbreviation())
+    }
+}
+
+impl FromStr for Direction {
+    type Err = NotationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "L" => Ok(Direction::Left),
+            "UL" => Ok(Direction::UpLeft),
+            "UR" => Ok(Direction::UpRight),
+            "R" => Ok(Direction::Right),
+            "DR" => Ok(Direction::DownRight),
+            "DL" => Ok(Direction::DownLeft),
+            _ => Err(NotationError::UnknownDirection(s.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for Coordinates {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let file = (b'a' as i8 + self.0 + 5) as u8 as char;
+        write!(f, "{}{}", file, self.1 + 6)
+    }
+}
+
+impl FromStr for Coordinates {
+    type Err = NotationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || NotationError::InvalidCoordinates(s.to_string());
+        let mut chars = s.chars();
+        let file = chars.next().filter(|c| ('a'..='k').contains(c)).ok_or_else(invalid)?;
+        // Digits only, without a sign or leading zero, so that every space has one spelling
+        let rank = chars.as_str();
+        if !rank.bytes().all(|b| b.is_ascii_digit()) || rank.starts_with('0') {
+            return Err(invalid());
+        }
+        let rank: i8 = rank.parse().map_err(|_| invalid())?;
+        let q = file as i8 - b'a' as i8 - 5;
+        let r = rank.checked_sub(6).ok_or_else(invalid)?;
+        let coordinates = Coordinates(q, r, -q - r);
+        if coordinates.is_off_board() {
+            return Err(invalid());
+        }
+        Ok(coordinates)
+    }
+}
+
+/// Splits a trailing run of digits off `s`, e.g. `"UR3"` becomes `("UR", "3")`.
+fn split_distance(s: &str) -> (&str, &str) {
+    let index = s.find(|c: char| c.is_ascii_digit()).unwrap_or(s.len());
+    s.split_at(index)
+}
+
+fn parse_distance(s: &str) -> Result<usize, NotationError> {
+    s.parse().map_err(|_| NotationError::InvalidDistance(s.to_string()))
+}
+
+fn parse_ability(token: Token, s: &str) -> Result<Ability, NotationError> {
+    let malformed = || NotationError::Malformed(format!("{}:{}", token.letter(), s));
+    match token {
+        Token::Hammer | Token::Hook => {
+            let (target, rest) = s.split_once('>').ok_or_else(malformed)?;
+            let (direction, distance) = split_distance(rest);
+            let target = target.parse()?;
+            let direction = direction.parse()?;
+            let distance = parse_distance(distance)?;
+            if token == Token::Hammer {
+                Ok(Ability::Hammer { target, direction, distance })
+            }
+            else {
+                Ok(Ability::Hook { target, direction, distance })
+            }
+        }
+        Token::Wave if s.contains('>') => {
+            let (origin, direction) = s.split_once('>').ok_or_else(malformed)?;
+            Ok(Ability::BuffedWave { origin: origin.parse()?, direction: direction.parse()? })
+        }
+        Token::Wave | Token::Scout => {
+            let (target, destination) = s.split_once('-').ok_or_else(malformed)?;
+            let target = target.parse()?;
+            let destination = destination.parse()?;
+            if token == Token::Wave {
+                Ok(Ability::Wave { target, destination })
+            }
+            else {
+                Ok(Ability::Scout { target, destination })
+            }
+        }
+        Token::Daimyo => {
+            let (target, rest) = s.split_once('-').ok_or_else(malformed)?;
+            let (destination, hand_direction) = match rest.split_once('/') {
+                Some((destination, hand_direction)) => (destination, Some(hand_direction.parse()?)),
+                None => (rest, None),
+            };
+            Ok(Ability::Daimyo { target: target.parse()?, destination: destination.parse()?, hand_direction })
+        }
+        Token::Bomb => Ok(Ability::Bomb { origin: s.parse()? }),
+        Token::Hand => {
+            let (origin, rest) = s.split_once('>').ok_or_else(malformed)?;
+            let (move_direction, hand_direction) = rest.split_once('/').ok_or_else(malformed)?;
+            Ok(Ability::Hand {
+                origin: origin.parse()?,
+                move_direction: move_direction.parse()?,
+                hand_direction: hand_direction.parse()?,
+            })
+        }
+    }
+}
+
+impl fmt::Display for Ability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Ability::Hammer { target, direction, distance } => write!(f, "M:{}>{}{}", target, direction, distance),
+            Ability::Hook { target, direction, distance } => write!(f, "K:{}>{}{}", target, direction, distance),
+            Ability::Wave { target, destination } => write!(f, "W:{}-{}", target, destination),
+            Ability::BuffedWave { origin, direction } => write!(f, "W:{}>{}", origin, direction),
+            Ability::Scout { target, destination } => write!(f, "S:{}-{}", target, destination),
+            Ability::Daimyo { target, destination, hand_direction: None } => write!(f, "D:{}-{}", target, destination),
+            Ability::Daimyo { target, destination, hand_direction: Some(hand_direction) } => {
+                write!(f, "D:{}-{}/{}", target, destination, hand_direction)
+            }
+            Ability::Bomb { origin } => write!(f, "B:{}", origin),
+            Ability::Hand { origin, move_direction, hand_direction } => {
+                write!(f, "H:{}>{}/{}", origin, move_direction, hand_direction)
+            }
+        }
+    }
+}
+
+impl fmt::Display for Choice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Choice::Pass => write!(f, "pass"),
+            Choice::Deploy(token, None) => write!(f, "+{}", token.letter()),
+            Choice::Deploy(token, Some(direction)) => write!(f, "+{}/{}", token.letter(), direction),
+            Choice::Move(token, direction) => write!(f, "{}>{}", token.letter(), direction),
+            Choice::UseAbility(ability) => write!(f, "{}", ability),
+            Choice::Revive(token) => write!(f, "^{}", token.letter()),
+        }
+    }
+}
+
+impl FromStr for Choice {
+    type Err = NotationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.eq_ignore_ascii_case("pass") {
+            return Ok(Choice::Pass);
+        }
+        if let Some(rest) = s.strip_prefix('+') {
+            return match rest.split_once('/') {
+                Some((token, direction)) => Ok(Choice::Deploy(parse_token(token)?, Some(direction.parse()?))),
+                None => Ok(Choice::Deploy(parse_token(rest)?, None)),
+            };
+        }
+        if let Some(rest) = s.strip_prefix('^') {
+            return Ok(Choice::Revive(parse_token(rest)?));
+        }
+        if let Some((token, rest)) = s.split_once(':') {
+            return Ok(Choice::UseAbility(parse_ability(parse_token(token)?, rest)?));
+        }
+        if let Some((token, direction)) = s.split_once('>') {
+            return Ok(Choice::Move(parse_token(token)?, direction.parse()?));
+        }
+        Err(NotationError::Malformed(s.to_string()))
+    }
+}
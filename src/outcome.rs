@@ -0,0 +1,144 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::board::{Color, Piece};
+use crate::coordinates::Coordinates;
+use crate::rules::Game;
+
+/// How a finished game ended.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct GameOutcome {
+    /// `None` for a draw.
+    pub winner: Option<Color>,
+    pub reason: OutcomeReason,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum OutcomeReason {
+    /// The bad rock reached a village, losing the game for that village's owner.
+    BadRockDelivered,
+    /// Both good rocks reached the same village, winning the game for its owner.
+    GoodRocksCollected,
+    /// Each village holds a good rock and the side of the board the bad rock is on decides
+    /// (`end-if-good-rock-in-both-villages`). Drawn when the bad rock is on the center line.
+    SplitGoodRocks,
+    /// The same position occurred for the third time.
+    Repetition,
+    /// The maximum number of turns was played.
+    TurnLimit,
+}
+
+/// The order rock outcomes are checked in when one action completes several of them at once.
+pub const DEFAULT_PRECEDENCE: [OutcomeReason; 3] = [
+    OutcomeReason::GoodRocksCollected,
+    OutcomeReason::BadRockDelivered,
+    OutcomeReason::SplitGoodRocks,
+];
+
+impl OutcomeReason {
+    pub fn name(&self) -> &'static str {
+        match self {
+            OutcomeReason::BadRockDelivered => "bad-rock",
+            OutcomeReason::GoodRocksCollected => "good-rocks",
+            OutcomeReason::SplitGoodRocks => "split-good-rocks",
+            OutcomeReason::Repetition => "repetition",
+            OutcomeReason::TurnLimit => "turns",
+        }
+    }
+}
+
+impl fmt::Display for OutcomeReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OutcomeReason::BadRockDelivered => write!(f, "bad rock delivered"),
+            OutcomeReason::GoodRocksCollected => write!(f, "both good rocks collected"),
+            OutcomeReason::SplitGoodRocks => write!(f, "good rocks split between the villages"),
+            OutcomeReason::Repetition => write!(f, "threefold repetition"),
+            OutcomeReason::TurnLimit => write!(f, "turn limit"),
+        }
+    }
+}
+
+/// Parses the names used in positions and rule sets, e.g. `bad-rock`.
+impl FromStr for OutcomeReason {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [
+            OutcomeReason::BadRockDelivered,
+            OutcomeReason::GoodRocksCollected,
+            OutcomeReason::SplitGoodRocks,
+            OutcomeReason::Repetition,
+            OutcomeReason::TurnLimit,
+        ]
+            .into_iter()
+            .find(|reason| reason.name() == s)
+            .ok_or_else(|| format!("Unknown outcome reason '{}'", s))
+    }
+}
+
+impl fmt::Display for GameOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.winner {
+            Some(winner) => write!(f, "{:?} wins by {}", winner, self.reason),
+            None => write!(f, "Drawn by {}", self.reason),
+        }
+    }
+}
+
+impl Game {
+    /// The outcome decided by the rock positions, if any, checking the rock outcomes in the
+    /// order of `RuleSet::precedence`.
+    pub fn rock_outcome(&self) -> Option<GameOutcome> {
+        let bad_rock = self.board.find(Piece::BadRock).unwrap();
+        let good_rock = self.board.find(Piece::GoodRock).unwrap();
+        let good_rock_2 = self.board.find(Piece::GoodRock2).unwrap();
+        self.rules.precedence.iter().find_map(|&reason| {
+            self.rock_winner(reason, bad_rock, good_rock, good_rock_2).map(|winner| GameOutcome { winner, reason })
+        })
+    }
+
+    /// `Some(winner)` when the rocks satisfy `reason`, where a winner of `None` is a draw.
+    fn rock_winner(
+        &self,
+        reason: OutcomeReason,
+        bad_rock: Coordinates,
+        good_rock: Coordinates,
+        good_rock_2: Coordinates,
+    ) -> Option<Option<Color>> {
+        let village_of = |coordinates| {
+            [Color::White, Color::Black].into_iter().find(|&color| self.board.is_in_village(coordinates, color))
+        };
+
+        match reason {
+            OutcomeReason::BadRockDelivered => village_of(bad_rock).map(|owner| Some(owner.opposite())),
+            OutcomeReason::GoodRocksCollected => match (village_of(good_rock), village_of(good_rock_2)) {
+                (Some(owner), Some(owner_2)) if owner == owner_2 => Some(Some(owner)),
+                _ => None,
+            },
+            OutcomeReason::SplitGoodRocks => {
+                if !self.rules.end_if_good_rock_in_both_villages {
+                    return None;
+                }
+                match (village_of(good_rock), village_of(good_rock_2)) {
+                    (Some(owner), Some(owner_2)) if owner != owner_2 => {}
+                    _ => return None,
+                }
+                // The bad rock's side of the board loses: Black's village is at negative r.
+                if bad_rock.1 < 0 {
+                    Some(Some(Color::White))
+                }
+                else if bad_rock.1 > 0 {
+                    Some(Some(Color::Black))
+                }
+                else if self.rules.draw_if_bad_rock_centered {
+                    Some(None)
+                }
+                else {
+                    None
+                }
+            }
+            OutcomeReason::Repetition | OutcomeReason::TurnLimit => None,
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use std::fmt;
+use std::ops::AddAssign;
+
+use crate::mcts::Game as MctsGame;
+
+use crate::choices::Choice;
+use crate::rules::Game;
+
+/// Leaf counts of a perft search, broken down by the kind of the last choice played.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct PerftCounts {
+    pub nodes: u64,
+    pub passes: u64,
+    pub deploys: u64,
+    pub moves: u64,
+    pub abilities: u64,
+    pub revives: u64,
+}
+
+impl PerftCounts {
+    fn leaf(choice: &Choice) -> PerftCounts {
+        let mut counts = PerftCounts { nodes: 1, ..PerftCounts::default() };
+        match choice {
+            Choice::Pass => counts.passes = 1,
+            Choice::Deploy(..) => counts.deploys = 1,
+            Choice::Move(..) => counts.moves = 1,
+            Choice::UseAbility(..) => counts.abilities = 1,
+            Choice::Revive(..) => counts.revives = 1,
+        }
+        counts
+    }
+}
+
+impl AddAssign for PerftCounts {
+    fn add_assign(&mut self, other: PerftCounts) {
+        self.nodes += other.nodes;
+        self.passes += other.passes;
+        self.deploys += other.deploys;
+        self.moves += other.moves;
+        self.abilities += other.abilities;
+        self.revives += other.revives;
+    }
+}
+
+impl fmt::Display for PerftCounts {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} (pass {}, deploy {}, move {}, ability {}, revive {})",
+            self.nodes, self.passes, self.deploys, self.moves, self.abilities, self.revives
+        )
+    }
+}
+
+/// Counts the positions reached by every sequence of `depth` legal choices. Games that end
+/// before `depth` choices are not counted.
+pub fn perft(game: &mut Game, depth: u32) -> u64 {
+    if depth == 0 {
+        return 1;
+    }
+    if game.is_terminal() {
+        return 0;
+    }
+    let mut nodes = 0;
+    for choice in game.get_all_choices() {
+        let undo = game.make(&choice);
+        nodes += perft(game, depth - 1);
+        game.unmake(undo);
+    }
+    nodes
+}
+
+/// Like `perft`, but also counts the leaves by the kind of choice that reached them.
+pub fn perft_counts(game: &mut Game, depth: u32) -> PerftCounts {
+    let mut counts = PerftCounts::default();
+    if depth == 0 || game.is_terminal() {
+        counts.nodes = if depth == 0 { 1 } else { 0 };
+        return counts;
+    }
+    for choice in game.get_all_choices() {
+        let undo = game.make(&choice);
+        if depth == 1 {
+            counts += PerftCounts::leaf(&choice);
+        }
+        else {
+            counts += perft_counts(game, depth - 1);
+        }
+        game.unmake(undo);
+    }
+    counts
+}
+
+/// The counts below each choice of the current position, for tracking down differences.
+pub fn divide(game: &mut Game, depth: u32) -> Vec<(Choice, u64)> {
+    if depth == 0 || game.is_terminal() {
+        return vec![];
+    }
+    game.get_all_choices()
+        .into_iter()
+        .map(|choice| {
+            let undo = game.make(&choice);
+            let nodes = perft(game, depth - 1);
+            game.unmake(undo);
+            (choice, nodes)
+        })
+        .collect()
+}
@@ -0,0 +1,322 @@
+//! Single-line text format for a complete game position.
+//!
+//! A position is eight fields separated by spaces:
+//!
+//! ```text
+//! 6/7/8/4d4/10/1Y3X3Z1/10/4D4/8/7/6 SMKWHB SMKWHB - - L,R w1 0
+//! ```
+//!
+//! 1. The board, one rank per `/` from rank 1 (Black's village) to rank 11 (White's village),
+//!    each rank read from its lowest file to its highest. White tokens use the uppercase token
+//!    letters from [`crate::notation`], Black tokens the lowercase ones, `X` is the bad rock,
+//!    `Y` and `Z` are the two good rocks and a number is a run of empty spaces.
+//! 2. White's supply, as token letters in supply order, or `-` when empty.
+//! 3. Black's supply.
+//! 4. White's graveyard.
+//! 5. Black's graveyard.
+//! 6. The White and Black Hand directions, separated by a comma.
+//! 7. The turn state: `w1` or `b1` for a first action, `w2` or `b2` for a second action
+//!    followed by the letter of the token used in the first action if any (e.g. `w2M`), and
+//!    `won:w`, `won:b` or `drawn` followed by the reason once the game is over, e.g.
+//!    `won:b:bad-rock` or `drawn:repetition`. The reasons are `bad-rock`, `good-rocks`,
+//!    `split-good-rocks`, `repetition` and `turns`.
+//! 8. The choice number.
+//!
+//! Every rock must be on the board exactly once and every token must be in exactly one of
+//! its owner's board, supply or graveyard.
+
+use std::error::Error;
+use std::fmt;
+
+use strum::IntoEnumIterator;
+
+use crate::board::{Board, Color, Piece, Space, Token};
+use crate::coordinates::{Coordinates, Direction};
+use crate::outcome::GameOutcome;
+use crate::rules::{Game, RuleSet, TurnState};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Location {
+    Board,
+    Supply,
+    Graveyard,
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Location::Board => write!(f, "on the board"),
+            Location::Supply => write!(f, "in the supply"),
+            Location::Graveyard => write!(f, "in the graveyard"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PositionError {
+    WrongFieldCount(usize),
+    WrongRankCount(usize),
+    WrongRankLength { rank: usize, length: usize, expected: usize },
+    UnknownPiece(char),
+    UnknownToken(char),
+    DuplicatePiece(Piece),
+    MissingRock(Piece),
+    TokenInTwoPlaces { color: Color, token: Token, first: Location, second: Location },
+    MissingToken { color: Color, token: Token },
+    InvalidHandDirections(String),
+    InvalidTurnState(String),
+    InvalidChoiceNumber(String),
+}
+
+fn describe(piece: Piece) -> String {
+    match piece {
+        Piece::BadRock => String::from("The bad rock"),
+        Piece::GoodRock => String::from("The first good rock"),
+        Piece::GoodRock2 => String::from("The second good rock"),
+        Piece::Token(color, token) => format!("{:?} {:?}", color, token),
+    }
+}
+
+impl fmt::Display for PositionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PositionError::WrongFieldCount(count) => write!(f, "Expected 8 fields but found {}", count),
+            PositionError::WrongRankCount(count) => write!(f, "Expected 11 ranks but found {}", count),
+            PositionError::WrongRankLength { rank, length, expected } => {
+                write!(f, "Rank {} has {} spaces but should have {}", rank, length, expected)
+            }
+            PositionError::UnknownPiece(c) => write!(f, "Unknown piece '{}'", c),
+            PositionError::UnknownToken(c) => write!(f, "Unknown token '{}'", c),
+            PositionError::DuplicatePiece(piece) => write!(f, "{} appears more than once", describe(*piece)),
+            PositionError::MissingRock(piece) => write!(f, "{} is not on the board", describe(*piece)),
+            PositionError::TokenInTwoPlaces { color, token, first, second } => {
+                write!(f, "{:?} {:?} is both {} and {}", color, token, first, second)
+            }
+            PositionError::MissingToken { color, token } => {
+                write!(f, "{:?} {:?} is not on the board, in the supply or in the graveyard", color, token)
+            }
+            PositionError::InvalidHandDirections(s) => write!(f, "Invalid Hand directions '{}'", s),
+            PositionError::InvalidTurnState(s) => write!(f, "Invalid turn state '{}'", s),
+            PositionError::InvalidChoiceNumber(s) => write!(f, "Invalid choice number '{}'", s),
+        }
+    }
+}
+
+impl Error for PositionError {}
+
+fn rank_range(r: i8) -> std::ops::RangeInclusive<i8> {
+    std::cmp::max(-5, -5 - r)..=std::cmp::min(5, 5 - r)
+}
+
+fn piece_letter(piece: Piece) -> char {
+    match piece {
+        Piece::BadRock => 'X',
+        Piece::GoodRock => 'Y',
+        Piece::GoodRock2 => 'Z',
+        Piece::Token(Color::White, token) => token.letter(),
+        Piece::Token(Color::Black, token) => token.letter().to_ascii_lowercase(),
+    }
+}
+
+fn piece_from_letter(letter: char) -> Option<Piece> {
+    match letter {
+        'X' => Some(Piece::BadRock),
+        'Y' => Some(Piece::GoodRock),
+        'Z' => Some(Piece::GoodRock2),
+        _ if letter.is_ascii_uppercase() => Token::from_letter(letter).map(|token| Piece::Token(Color::White, token)),
+        _ => Token::from_letter(letter.to_ascii_uppercase()).map(|token| Piece::Token(Color::Black, token)),
+    }
+}
+
+fn tokens_to_notation(tokens: &[Token]) -> String {
+    if tokens.is_empty() {
+        String::from("-")
+    }
+    else {
+        tokens.iter().map(Token::letter).collect()
+    }
+}
+
+fn tokens_from_notation(s: &str) -> Result<Vec<Token>, PositionError> {
+    if s == "-" {
+        return Ok(vec![]);
+    }
+    s.chars().map(|c| Token::from_letter(c).ok_or(PositionError::UnknownToken(c))).collect()
+}
+
+fn turn_state_to_notation(turn_state: &TurnState) -> String {
+    match turn_state {
+        TurnState::WhiteFirstAction => String::from("w1"),
+        TurnState::BlackFirstAction => String::from("b1"),
+        TurnState::WhiteSecondAction { used_piece } => format!("w2{}", used_piece.map(|t| t.letter().to_string()).unwrap_or_default()),
+        TurnState::BlackSecondAction { used_piece } => format!("b2{}", used_piece.map(|t| t.letter().to_string()).unwrap_or_default()),
+        TurnState::Over(GameOutcome { winner: Some(Color::White), reason }) => format!("won:w:{}", reason.name()),
+        TurnState::Over(GameOutcome { winner: Some(Color::Black), reason }) => format!("won:b:{}", reason.name()),
+        TurnState::Over(GameOutcome { winner: None, reason }) => format!("drawn:{}", reason.name()),
+    }
+}
+
+fn turn_state_from_notation(s: &str) -> Result<TurnState, PositionError> {
+    let invalid = || PositionError::InvalidTurnState(s.to_string());
+    match s {
+        "w1" => return Ok(TurnState::WhiteFirstAction),
+        "b1" => return Ok(TurnState::BlackFirstAction),
+        _ => {}
+    }
+    if let Some((result, reason)) = s.rsplit_once(':') {
+        let winner = match result {
+            "won:w" => Some(Color::White),
+            "won:b" => Some(Color::Black),
+            "drawn" => None,
+            _ => return Err(invalid()),
+        };
+        let reason = reason.parse().map_err(|_| invalid())?;
+        return Ok(TurnState::Over(GameOutcome { winner, reason }));
+    }
+    let mut chars = s.chars();
+    let prefix: String = chars.by_ref().take(2).collect();
+    let used_piece = match chars.collect::<Vec<char>>()[..] {
+        [] => None,
+        [letter] => Some(Token::from_letter(letter).ok_or_else(invalid)?),
+        _ => return Err(invalid()),
+    };
+    match prefix.as_str() {
+        "w2" => Ok(TurnState::WhiteSecondAction { used_piece }),
+        "b2" => Ok(TurnState::BlackSecondAction { used_piece }),
+        _ => Err(invalid()),
+    }
+}
+
+fn board_from_notation(s: &str) -> Result<Board, PositionError> {
+    let ranks: Vec<&str> = s.split('/').collect();
+    if ranks.len() != 11 {
+        return Err(PositionError::WrongRankCount(ranks.len()));
+    }
+    let mut board = Board::empty();
+    let mut placed: Vec<Piece> = vec![];
+    for (rank, (r, contents)) in (-5..=5).zip(ranks).enumerate() {
+        let files: Vec<i8> = rank_range(r).collect();
+        let mut index = 0;
+        let mut empty_run = 0;
+        let mut chars = contents.chars().peekable();
+        while let Some(c) = chars.next() {
+            if let Some(digit) = c.to_digit(10) {
+                empty_run = empty_run * 10 + digit as usize;
+                // Stop at the end of the rank, before a long run of digits overflows
+                if index + empty_run > files.len() {
+                    return Err(PositionError::WrongRankLength { rank: rank + 1, length: index + empty_run, expected: files.len() });
+                }
+                if chars.peek().is_none_or(|next| !next.is_ascii_digit()) {
+                    index += empty_run;
+                    empty_run = 0;
+                }
+                continue;
+            }
+            let piece = piece_from_letter(c).ok_or(PositionError::UnknownPiece(c))?;
+            if placed.contains(&piece) {
+                return Err(PositionError::DuplicatePiece(piece));
+            }
+            placed.push(piece);
+            if let Some(&q) = files.get(index) {
+                board.set_space(Coordinates(q, r, -q - r), Space::Occupied(piece));
+            }
+            index += 1;
+        }
+        if index != files.len() {
+            return Err(PositionError::WrongRankLength { rank: rank + 1, length: index, expected: files.len() });
+        }
+    }
+    for rock in [Piece::BadRock, Piece::GoodRock, Piece::GoodRock2] {
+        if !placed.contains(&rock) {
+            return Err(PositionError::MissingRock(rock));
+        }
+    }
+    Ok(board)
+}
+
+fn hand_directions_from_notation(s: &str) -> Result<[Direction; 2], PositionError> {
+    let invalid = || PositionError::InvalidHandDirections(s.to_string());
+    let (white, black) = s.split_once(',').ok_or_else(invalid)?;
+    Ok([white.parse().map_err(|_| invalid())?, black.parse().map_err(|_| invalid())?])
+}
+
+fn check_tokens(game: &Game) -> Result<(), PositionError> {
+    for color in [Color::White, Color::Black] {
+        for token in Token::iter() {
+            let mut locations = vec![];
+            if game.board.find(Piece::Token(color, token)).is_some() {
+                locations.push(Location::Board);
+            }
+            for (location, tokens) in [
+                (Location::Supply, &game.supplies[color as usize]),
+                (Location::Graveyard, &game.graveyards[color as usize]),
+            ] {
+                for _ in tokens.iter().filter(|&&t| t == token) {
+                    locations.push(location);
+                }
+            }
+            match locations[..] {
+                [] => return Err(PositionError::MissingToken { color, token }),
+                [_] => {}
+                [first, second, ..] => return Err(PositionError::TokenInTwoPlaces { color, token, first, second }),
+            }
+        }
+    }
+    Ok(())
+}
+
+impl Game {
+    pub fn to_notation(&self) -> String {
+        let mut ranks = vec![];
+        for r in -5..=5 {
+            let mut rank = String::new();
+            let mut empty_run = 0;
+            for q in rank_range(r) {
+                match self.board.get_space(Coordinates(q, r, -q - r)) {
+                    Space::Empty => empty_run += 1,
+                    Space::Occupied(piece) => {
+                        if empty_run > 0 {
+                            rank.push_str(&empty_run.to_string());
+                            empty_run = 0;
+                        }
+                        rank.push(piece_letter(piece));
+                    }
+                }
+            }
+            if empty_run > 0 {
+                rank.push_str(&empty_run.to_string());
+            }
+            ranks.push(rank);
+        }
+        format!(
+            "{} {} {} {} {} {},{} {} {}",
+            ranks.join("/"),
+            tokens_to_notation(&self.supplies[Color::White as usize]),
+            tokens_to_notation(&self.supplies[Color::Black as usize]),
+            tokens_to_notation(&self.graveyards[Color::White as usize]),
+            tokens_to_notation(&self.graveyards[Color::Black as usize]),
+            self.hand_directions[Color::White as usize],
+            self.hand_directions[Color::Black as usize],
+            turn_state_to_notation(&self.turn_state),
+            self.choice_number,
+        )
+    }
+
+    /// Reads a position written by `to_notation`. The game is played with the default rules.
+    pub fn from_notation(s: &str) -> Result<Game, PositionError> {
+        let fields: Vec<&str> = s.split_whitespace().collect();
+        if fields.len() != 8 {
+            return Err(PositionError::WrongFieldCount(fields.len()));
+        }
+        let mut game = Game::with_rules(RuleSet::default());
+        game.board = board_from_notation(fields[0])?;
+        game.supplies = [tokens_from_notation(fields[1])?, tokens_from_notation(fields[2])?];
+        game.graveyards = [tokens_from_notation(fields[3])?, tokens_from_notation(fields[4])?];
+        game.hand_directions = hand_directions_from_notation(fields[5])?;
+        game.turn_state = turn_state_from_notation(fields[6])?;
+        game.choice_number = fields[7].parse().map_err(|_| PositionError::InvalidChoiceNumber(fields[7].to_string()))?;
+        check_tokens(&game)?;
+        game.reset_history();
+        Ok(game)
+    }
+}
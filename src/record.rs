@@ -0,0 +1,378 @@
+//! Game records: header tags followed by the choices of a game in [`crate::notation`].
+//!
+//! ```text
+//! [Rules "all"]
+//! [White "MCTS"]
+//! [Black "MCTS"]
+//! [Iterations "1000000"]
+//! [Result "1-0"]
+//!
+//! 1. +M M:f6>UR2 S>L pass 2. D>R ...
+//! 1-0
+//! ```
+//!
+//! Every header line is a tag name and a quoted value. `Rules` holds the [`RuleSet`], `Result`
+//! holds `1-0` (White won), `0-1` (Black won), `1/2-1/2` (draw) or `*` (unfinished) and the
+//! optional `Position` tag holds the starting position in [`crate::position`] format when the
+//! game did not start from the initial position. A finished game names the reason it ended
+//! in the `Termination` tag. Any other tags, such as the players and search settings, are kept as
+//! they are.
+//!
+//! The move list numbers every four choices, one turn of each player. Numbers are only for the reader and are
+//! ignored when parsing. Comments in braces, such as `{White passes}`, may follow any choice. The
+//! list ends with the result.
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::mcts::Game as MctsGame;
+
+use crate::board::Color;
+use crate::choices::Choice;
+use crate::notation::NotationError;
+use crate::outcome::GameOutcome;
+use crate::position::PositionError;
+use crate::rules::{Game, RuleSet, TurnState};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GameResult {
+    WhiteWins,
+    BlackWins,
+    Draw,
+    Unfinished,
+}
+
+impl GameResult {
+    pub fn won_by(color: Color) -> Self {
+        match color {
+            Color::White => GameResult::WhiteWins,
+            Color::Black => GameResult::BlackWins,
+        }
+    }
+
+    pub fn of(game: &Game) -> Self {
+        match game.turn_state {
+            TurnState::Over(GameOutcome { winner: Some(Color::White), .. }) => GameResult::WhiteWins,
+            TurnState::Over(GameOutcome { winner: Some(Color::Black), .. }) => GameResult::BlackWins,
+            TurnState::Over(GameOutcome { winner: None, .. }) => GameResult::Draw,
+            _ => GameResult::Unfinished,
+        }
+    }
+}
+
+impl fmt::Display for GameResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameResult::WhiteWins => write!(f, "1-0"),
+            GameResult::BlackWins => write!(f, "0-1"),
+            GameResult::Draw => write!(f, "1/2-1/2"),
+            GameResult::Unfinished => write!(f, "*"),
+        }
+    }
+}
+
+impl FromStr for GameResult {
+    type Err = RecordError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1-0" => Ok(GameResult::WhiteWins),
+            "0-1" => Ok(GameResult::BlackWins),
+            "1/2-1/2" => Ok(GameResult::Draw),
+            "*" => Ok(GameResult::Unfinished),
+            _ => Err(RecordError::InvalidResult(s.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RecordError {
+    MalformedTag(String),
+    InvalidRules(String),
+    InvalidResult(String),
+    InvalidPosition(PositionError),
+    InvalidChoice { index: usize, error: NotationError },
+    UnclosedComment,
+}
+
+impl fmt::Display for RecordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RecordError::MalformedTag(line) => write!(f, "Malformed tag line '{}'", line),
+            RecordError::InvalidRules(message) => write!(f, "Invalid Rules tag: {}", message),
+            RecordError::InvalidResult(s) => write!(f, "Invalid result '{}'", s),
+            RecordError::InvalidPosition(error) => write!(f, "Invalid Position tag: {}", error),
+            RecordError::InvalidChoice { index, error } => write!(f, "Choice {}: {}", index + 1, error),
+            RecordError::UnclosedComment => write!(f, "A comment is missing its closing brace"),
+        }
+    }
+}
+
+impl Error for RecordError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReplayError {
+    InvalidPosition(PositionError),
+    GameAlreadyOver { index: usize, choice: Choice },
+    IllegalChoice { index: usize, choice: Choice },
+    ResultMismatch { recorded: GameResult, actual: GameResult },
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReplayError::InvalidPosition(error) => write!(f, "Invalid starting position: {}", error),
+            ReplayError::GameAlreadyOver { index, choice } => {
+                write!(f, "Choice {} ({}) was made after the game ended", index + 1, choice)
+            }
+            ReplayError::IllegalChoice { index, choice } => write!(f, "Choice {} ({}) is not legal", index + 1, choice),
+            ReplayError::ResultMismatch { recorded, actual } => {
+                write!(f, "Recorded result {} does not match the replayed result {}", recorded, actual)
+            }
+        }
+    }
+}
+
+impl Error for ReplayError {}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameRecord {
+    pub rules: RuleSet,
+    /// The starting position, when it is not the initial position.
+    pub start: Option<String>,
+    /// Any other header tags, in order.
+    pub tags: Vec<(String, String)>,
+    pub choices: Vec<Choice>,
+    /// Comments in the move list, each with the number of choices it follows. Comments cannot
+    /// contain `}`.
+    pub comments: Vec<(usize, String)>,
+    pub result: GameResult,
+}
+
+impl GameRecord {
+    /// Starts an empty record of a game that begins at `start`.
+    pub fn new(start: &Game) -> Self {
+        let position = start.to_notation();
+        GameRecord {
+            rules: start.rules,
+            start: if position == Game::with_rules(start.rules).to_notation() { None } else { Some(position) },
+            tags: vec![],
+            choices: vec![],
+            comments: vec![],
+            result: GameResult::of(start),
+        }
+    }
+
+    pub fn set_tag(&mut self, name: &str, value: &str) {
+        match self.tags.iter_mut().find(|(tag, _)| tag == name) {
+            Some((_, existing)) => *existing = value.to_string(),
+            None => self.tags.push((name.to_string(), value.to_string())),
+        }
+    }
+
+    pub fn tag(&self, name: &str) -> Option<&str> {
+        self.tags.iter().find(|(tag, _)| tag == name).map(|(_, value)| value.as_str())
+    }
+
+    /// Records a choice that was just applied, leaving `game` as the current state. The
+    /// reason the game ended, if it did, goes in the `Termination` tag.
+    pub fn push(&mut self, choice: Choice, game: &Game) {
+        self.choices.push(choice);
+        self.result = GameResult::of(game);
+        if let TurnState::Over(outcome) = game.turn_state {
+            self.set_tag("Termination", &outcome.reason.to_string());
+        }
+    }
+
+    /// Adds a comment after the last choice recorded so far.
+    pub fn comment(&mut self, text: &str) {
+        self.comments.push((self.choices.len(), text.replace('}', ")")));
+    }
+
+    /// Takes back the last choice together with the comments that follow it.
+    pub fn pop(&mut self) -> Option<Choice> {
+        let choice = self.choices.pop()?;
+        let length = self.choices.len();
+        self.comments.retain(|&(position, _)| position <= length);
+        Some(choice)
+    }
+
+    pub fn start_game(&self) -> Result<Game, PositionError> {
+        let mut game = match &self.start {
+            Some(position) => Game::from_notation(position)?,
+            None => Game::new(),
+        };
+        game.rules = self.rules;
+        Ok(game)
+    }
+
+    /// Plays every recorded choice from the starting position, checking that each one was
+    /// legal and that the game ends with the recorded result. A game that was stopped before
+    /// it ended, e.g. by resignation, may record any result.
+    pub fn replay(&self) -> Result<Game, ReplayError> {
+        let mut game = self.start_game().map_err(ReplayError::InvalidPosition)?;
+        for (index, choice) in self.choices.iter().enumerate() {
+            if game.is_terminal() {
+                return Err(ReplayError::GameAlreadyOver { index, choice: *choice });
+            }
+            if !game.get_all_choices().contains(choice) {
+                return Err(ReplayError::IllegalChoice { index, choice: *choice });
+            }
+            game.apply_choice(choice);
+        }
+        let actual = GameResult::of(&game);
+        if actual != GameResult::Unfinished && actual != self.result {
+            return Err(ReplayError::ResultMismatch { recorded: self.result, actual });
+        }
+        Ok(game)
+    }
+}
+
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn parse_tag(line: &str) -> Result<(String, String), RecordError> {
+    let malformed = || RecordError::MalformedTag(line.to_string());
+    let inner = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')).ok_or_else(malformed)?;
+    let (name, quoted) = inner.split_once(' ').ok_or_else(malformed)?;
+    let quoted = quoted.trim().strip_prefix('"').and_then(|q| q.strip_suffix('"')).ok_or_else(malformed)?;
+    let mut value = String::new();
+    let mut chars = quoted.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => value.push(chars.next().ok_or_else(malformed)?),
+            '"' => return Err(malformed()),
+            _ => value.push(c),
+        }
+    }
+    Ok((name.to_string(), value))
+}
+
+fn is_turn_number(word: &str) -> bool {
+    let digits = word.trim_end_matches('.');
+    digits.len() < word.len() && !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit())
+}
+
+impl GameRecord {
+    fn write_comments(&self, line: &mut String, position: usize) {
+        for (_, text) in self.comments.iter().filter(|(at, _)| *at == position) {
+            line.push_str(&format!("{{{}}} ", text));
+        }
+    }
+}
+
+/// A word or a comment of the move list.
+enum MoveText<'a> {
+    Word(&'a str),
+    Comment(&'a str),
+}
+
+fn split_move_text(text: &str) -> Result<Vec<MoveText<'_>>, RecordError> {
+    let mut items = vec![];
+    let mut rest = text;
+    while let Some(open) = rest.find('{') {
+        items.extend(rest[..open].split_whitespace().map(MoveText::Word));
+        let close = rest[open..].find('}').ok_or(RecordError::UnclosedComment)? + open;
+        items.push(MoveText::Comment(rest[open + 1..close].trim()));
+        rest = &rest[close + 1..];
+    }
+    items.extend(rest.split_whitespace().map(MoveText::Word));
+    Ok(items)
+}
+
+impl fmt::Display for GameRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "[Rules \"{}\"]", self.rules)?;
+        for (name, value) in self.tags.iter() {
+            writeln!(f, "[{} \"{}\"]", name, escape(value))?;
+        }
+        if let Some(position) = &self.start {
+            writeln!(f, "[Position \"{}\"]", position)?;
+        }
+        writeln!(f, "[Result \"{}\"]", self.result)?;
+        writeln!(f)?;
+
+        let first_choice_number = self.start_game().map(|game| game.choice_number).unwrap_or(0) as usize;
+        let mut line = String::new();
+        for (index, choice) in self.choices.iter().enumerate() {
+            self.write_comments(&mut line, index);
+            let choice_number = first_choice_number + index;
+            if choice_number.is_multiple_of(4) {
+                if !line.is_empty() {
+                    writeln!(f, "{}", line.trim_end())?;
+                    line.clear();
+                }
+                line.push_str(&format!("{}. ", choice_number / 4 + 1));
+            }
+            else if index == 0 {
+                line.push_str(&format!("{}... ", choice_number / 4 + 1));
+            }
+            line.push_str(&format!("{} ", choice));
+        }
+        self.write_comments(&mut line, self.choices.len());
+        if !line.is_empty() {
+            writeln!(f, "{}", line.trim_end())?;
+        }
+        writeln!(f, "{}", self.result)
+    }
+}
+
+impl FromStr for GameRecord {
+    type Err = RecordError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut record = GameRecord {
+            rules: RuleSet::default(),
+            start: None,
+            tags: vec![],
+            choices: vec![],
+            comments: vec![],
+            result: GameResult::Unfinished,
+        };
+        let mut tagged_result = None;
+        let mut move_text = String::new();
+        for line in s.lines().map(str::trim) {
+            if line.starts_with('[') {
+                let (name, value) = parse_tag(line)?;
+                match name.as_str() {
+                    "Rules" => record.rules = value.parse().map_err(RecordError::InvalidRules)?,
+                    "Position" => {
+                        Game::from_notation(&value).map_err(RecordError::InvalidPosition)?;
+                        record.start = Some(value);
+                    }
+                    "Result" => tagged_result = Some(value.parse()?),
+                    _ => record.tags.push((name, value)),
+                }
+            }
+            else {
+                move_text.push_str(line);
+                move_text.push('\n');
+            }
+        }
+
+        let mut items = split_move_text(&move_text)?;
+        if let Some(MoveText::Word(word)) = items.last() {
+            if let Ok(result) = word.parse() {
+                record.result = result;
+                items.pop();
+            }
+        }
+        if let Some(result) = tagged_result {
+            record.result = result;
+        }
+
+        for item in items {
+            match item {
+                MoveText::Word(word) if is_turn_number(word) => {}
+                MoveText::Word(word) => {
+                    let choice = word.parse().map_err(|error| RecordError::InvalidChoice { index: record.choices.len(), error })?;
+                    record.choices.push(choice);
+                }
+                MoveText::Comment(text) => record.comments.push((record.choices.len(), text.to_string())),
+            }
+        }
+        Ok(record)
+    }
+}
@@ -0,0 +1,158 @@
+//! The playout policy of the search. Uniform playouts mostly pass, turn the Hand and try every
+//! distance of the Hook, so instead choices are weighted by how they change the features of
+//! the evaluation: moving the bad rock toward the opponent's village and capturing are
+//! favored, and losing tokens to the pit or the edge is avoided.
+
+use std::fmt;
+use std::str::FromStr;
+
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::board::Color;
+use crate::choices::Choice;
+use crate::evaluation::Features;
+use crate::mcts::Game as MctsGame;
+use crate::mcts::Policy;
+use crate::rules::{Game, TurnState};
+
+/// A choice is picked with probability proportional to `exp` of the changes to the features
+/// it makes, times their weights, among a few candidates. All zero is uniform.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct RolloutWeights {
+    /// Per step the bad rock moves toward the opponent's village.
+    pub bad_rock: f64,
+    /// Per step a good rock moves toward the player's village.
+    pub good_rocks: f64,
+    /// Per token captured, or lost with a negative change, e.g. in the pit.
+    pub material: f64,
+    /// For endangering the opponent's Daimyo or saving the player's.
+    pub daimyo: f64,
+    /// Per token of the opponent pushed to the edge, or of the player's leaving it.
+    pub edge: f64,
+    /// For winning the game, or losing it with a negative change.
+    pub win: f64,
+    /// How many choices, drawn uniformly, are weighed at every step. Weighing all of them
+    /// costs more time than it gains.
+    pub candidates: usize,
+}
+
+const NAMES: [&str; 6] = ["bad-rock", "good-rocks", "material", "daimyo", "edge", "win"];
+
+impl RolloutWeights {
+    pub fn uniform() -> Self {
+        RolloutWeights { bad_rock: 0.0, good_rocks: 0.0, material: 0.0, daimyo: 0.0, edge: 0.0, win: 0.0, ..RolloutWeights::default() }
+    }
+
+    pub fn is_uniform(&self) -> bool {
+        self.candidates <= 1 || self.weights() == [0.0; 6]
+    }
+
+    fn weight_mut(&mut self, name: &str) -> Option<&mut f64> {
+        match name {
+            "bad-rock" => Some(&mut self.bad_rock),
+            "good-rocks" => Some(&mut self.good_rocks),
+            "material" => Some(&mut self.material),
+            "daimyo" => Some(&mut self.daimyo),
+            "edge" => Some(&mut self.edge),
+            "win" => Some(&mut self.win),
+            _ => None,
+        }
+    }
+
+    fn weights(&self) -> [f64; 6] {
+        [self.bad_rock, self.good_rocks, self.material, self.daimyo, self.edge, self.win]
+    }
+
+    /// The exponent of the weight of the choice that led from `before` to `game`.
+    fn score(&self, game: &Game, color: Color, before: Features) -> f64 {
+        let after = game.features(color);
+        let outcome = match game.turn_state {
+            TurnState::Over(outcome) => match outcome.winner {
+                Some(winner) if winner == color => 1.0,
+                Some(_) => -1.0,
+                None => 0.0,
+            },
+            _ => 0.0,
+        };
+        self.bad_rock * (after.bad_rock - before.bad_rock)
+            + self.good_rocks * (after.good_rocks - before.good_rocks)
+            + self.material * (after.material - before.material)
+            + self.daimyo * (after.daimyo - before.daimyo)
+            + self.edge * (after.edge - before.edge)
+            + self.win * outcome
+    }
+}
+
+impl Default for RolloutWeights {
+    fn default() -> Self {
+        RolloutWeights { bad_rock: 1.0, good_rocks: 0.5, material: 1.5, daimyo: 1.0, edge: 0.5, win: 4.0, candidates: 6 }
+    }
+}
+
+impl Policy<Game> for RolloutWeights {
+    fn choose(&self, game: &mut Game, choices: &[Choice], rng: &mut StdRng) -> Choice {
+        if self.is_uniform() {
+            return choices[rng.gen_range(0..choices.len())];
+        }
+        let candidates: Vec<Choice> = choices.choose_multiple(rng, self.candidates).copied().collect();
+        let color = game.get_active_player_id();
+        let before = game.features(color);
+        let scores: Vec<f64> = candidates
+            .iter()
+            .map(|choice| {
+                let undo = game.make(choice);
+                let score = self.score(game, color, before);
+                game.unmake(undo);
+                score
+            })
+            .collect();
+        // Relative to the best score, so that `exp` neither overflows nor underflows to all zero
+        let best = scores.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        match WeightedIndex::new(scores.iter().map(|score| (score - best).exp())) {
+            Ok(index) => candidates[index.sample(rng)],
+            // Scores that are not numbers, e.g. from infinite weights cancelling out
+            Err(_) => candidates[rng.gen_range(0..candidates.len())],
+        }
+    }
+}
+
+impl fmt::Display for RolloutWeights {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_uniform() {
+            return write!(f, "uniform");
+        }
+        let weights: Vec<String> = NAMES.iter().zip(self.weights()).map(|(name, weight)| format!("{}={}", name, weight)).collect();
+        write!(f, "{},candidates={}", weights.join(","), self.candidates)
+    }
+}
+
+/// Parses a comma-separated list of `uniform`, which sets every weight to zero, weights
+/// changed from the defaults and `candidates=<n>`, e.g. `bad-rock=2,edge=0` or
+/// `uniform,material=1,candidates=10`.
+impl FromStr for RolloutWeights {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut weights = RolloutWeights::default();
+        for item in s.split(',').map(str::trim) {
+            if item == "uniform" {
+                weights = RolloutWeights { candidates: weights.candidates, ..RolloutWeights::uniform() };
+                continue;
+            }
+            let (name, value) = item.split_once('=').ok_or_else(|| format!("Expected <weight>=<value> but found '{}'", item))?;
+            if name == "candidates" {
+                weights.candidates = value.parse().map_err(|_| format!("Invalid number of candidates '{}'", value))?;
+                continue;
+            }
+            let value = value.parse().ok().filter(|value: &f64| value.is_finite()).ok_or_else(|| format!("Invalid value '{}' for {}", value, name))?;
+            match weights.weight_mut(name) {
+                Some(weight) => *weight = value,
+                None => return Err(format!("Unknown rollout weight '{}' (expected uniform or one of {})", name, NAMES.join(", "))),
+            }
+        }
+        Ok(weights)
+    }
+}
@@ -0,0 +1,965 @@
+use std::fmt;
+use std::str::FromStr;
+
+use strum::IntoEnumIterator;
+
+use crate::mcts::Game as MctsGame;
+
+use crate::bitboard::{self, Bitboard};
+use crate::board::{Board, Color, Piece, Space, Token};
+use crate::choices::{Ability, Choice};
+use crate::coordinates::{Coordinates, Direction, CENTER_SPACE};
+use crate::events::Event;
+use crate::outcome::{GameOutcome, OutcomeReason, DEFAULT_PRECEDENCE};
+use crate::rulings::{HandDeployOrientation, HandGrab, Rulings};
+use crate::zobrist;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TurnState {
+    WhiteFirstAction,
+    WhiteSecondAction { used_piece: Option<Token> },
+    BlackFirstAction,
+    BlackSecondAction { used_piece: Option<Token> },
+    Over(GameOutcome),
+}
+
+impl TurnState {
+    // TODO This is a terrible name. How can I explain what I'm doing here?
+    pub fn get_matchable(&self) -> Option<(Color, Option<Token>)> {
+        match self {
+            TurnState::WhiteFirstAction => Some((Color::White, None)),
+            TurnState::WhiteSecondAction { used_piece } => Some((Color::White, *used_piece)),
+            TurnState::BlackFirstAction => Some((Color::Black, None)),
+            TurnState::BlackSecondAction { used_piece } => Some((Color::Black, *used_piece)),
+            TurnState::Over(_) => None,
+        }
+    }
+
+    pub fn get_color(&self) -> Option<Color> {
+        if let Some((color, _)) = self.get_matchable() {
+            Some(color)
+        }
+        else {
+            None
+        }
+    }
+}
+
+/// The optional rule variants in play.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct RuleSet {
+    /// Tokens that end an action on the center space are removed from the board.
+    pub pit_of_misfortune: bool,
+    /// Tokens removed from the board go to the graveyard instead of back to the supply.
+    pub perma_death: bool,
+    /// Players may spend an action to return a token from their graveyard to their supply.
+    pub revive_action: bool,
+    /// The game ends when each village holds a good rock; the bad rock's side decides the winner.
+    pub end_if_good_rock_in_both_villages: bool,
+    /// With the good rocks split between the villages and the bad rock on the center line the
+    /// game is drawn, rather than continuing until the bad rock leaves the line.
+    pub draw_if_bad_rock_centered: bool,
+    /// The game is drawn when the same position occurs for the third time.
+    pub draw_by_repetition: bool,
+    /// The Wave reflects the whole line of pieces next to it to its other side instead of a
+    /// single piece. An alternative design rather than a variant, so `all` leaves it out.
+    pub buffed_wave: bool,
+    /// The game is drawn after this many turns (one turn is both actions of one player).
+    pub max_turns: Option<u32>,
+    /// The order the rock outcomes are checked in, deciding which one counts when a single
+    /// action completes several.
+    pub precedence: [OutcomeReason; 3],
+    pub rulings: Rulings,
+}
+
+const VARIANT_NAMES: [&str; 6] = [
+    "pit-of-misfortune",
+    "perma-death",
+    "revive-action",
+    "end-if-good-rock-in-both-villages",
+    "draw-if-bad-rock-centered",
+    "draw-by-repetition",
+];
+
+impl RuleSet {
+    /// The rules as printed, with no variants enabled.
+    pub fn base() -> Self {
+        RuleSet {
+            pit_of_misfortune: false,
+            perma_death: false,
+            revive_action: false,
+            end_if_good_rock_in_both_villages: false,
+            draw_if_bad_rock_centered: false,
+            draw_by_repetition: false,
+            buffed_wave: false,
+            max_turns: None,
+            precedence: DEFAULT_PRECEDENCE,
+            rulings: Rulings::default(),
+        }
+    }
+
+    /// Every variant enabled, except the draws, which end games the other variants play out
+    /// and have to be asked for.
+    pub fn all_variants() -> Self {
+        RuleSet {
+            pit_of_misfortune: true,
+            perma_death: true,
+            revive_action: true,
+            end_if_good_rock_in_both_villages: true,
+            draw_if_bad_rock_centered: false,
+            draw_by_repetition: false,
+            buffed_wave: false,
+            max_turns: None,
+            precedence: DEFAULT_PRECEDENCE,
+            rulings: Rulings::default(),
+        }
+    }
+
+    /// Named presets accepted by `FromStr`, in addition to explicit variant lists.
+    pub fn presets() -> [(&'static str, RuleSet); 2] {
+        [
+            ("base", RuleSet::base()),
+            ("all", RuleSet::all_variants()),
+        ]
+    }
+
+    fn variant_mut(&mut self, name: &str) -> Option<&mut bool> {
+        match name {
+            "pit-of-misfortune" => Some(&mut self.pit_of_misfortune),
+            "perma-death" => Some(&mut self.perma_death),
+            "revive-action" => Some(&mut self.revive_action),
+            "end-if-good-rock-in-both-villages" => Some(&mut self.end_if_good_rock_in_both_villages),
+            "draw-if-bad-rock-centered" => Some(&mut self.draw_if_bad_rock_centered),
+            "draw-by-repetition" => Some(&mut self.draw_by_repetition),
+            _ => None,
+        }
+    }
+
+    fn enabled_variants(&self) -> impl Iterator<Item = &'static str> {
+        let flags = [
+            self.pit_of_misfortune,
+            self.perma_death,
+            self.revive_action,
+            self.end_if_good_rock_in_both_villages,
+            self.draw_if_bad_rock_centered,
+            self.draw_by_repetition,
+        ];
+        VARIANT_NAMES.into_iter().zip(flags).filter(|(_, enabled)| *enabled).map(|(name, _)| name)
+    }
+}
+
+impl Default for RuleSet {
+    fn default() -> Self {
+        RuleSet::all_variants()
+    }
+}
+
+impl fmt::Display for RuleSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let variants = RuleSet {
+            draw_if_bad_rock_centered: false,
+            draw_by_repetition: false,
+            buffed_wave: false,
+            max_turns: None,
+            precedence: DEFAULT_PRECEDENCE,
+            rulings: Rulings::default(),
+            ..*self
+        };
+        let mut names: Vec<String> = match RuleSet::presets().iter().find(|(_, rules)| *rules == variants) {
+            Some((name, _)) => vec![name.to_string()],
+            None => variants.enabled_variants().map(String::from).collect(),
+        };
+        if self.draw_if_bad_rock_centered {
+            names.push(String::from("draw-if-bad-rock-centered"));
+        }
+        if self.draw_by_repetition {
+            names.push(String::from("draw-by-repetition"));
+        }
+        if self.buffed_wave {
+            names.push(String::from("buffed-wave"));
+        }
+        names.extend(self.rulings.items());
+        if let Some(max_turns) = self.max_turns {
+            names.push(format!("max-turns={}", max_turns));
+        }
+        if self.precedence != DEFAULT_PRECEDENCE {
+            let order: Vec<&str> = self.precedence.iter().map(OutcomeReason::name).collect();
+            names.push(format!("precedence={}", order.join(">")));
+        }
+        write!(f, "{}", names.join(","))
+    }
+}
+
+/// Parses a comma-separated list of a preset name (`base`, `all`), the variants to enable,
+/// `buffed-wave`, the rulings (see [`crate::rulings`]), an optional turn limit and an optional
+/// precedence of the rock outcomes, e.g. `pit-of-misfortune,revive-action` or
+/// `all,buffed-wave,step-off-edge,max-turns=200,precedence=bad-rock>good-rocks>split-good-rocks`.
+impl FromStr for RuleSet {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut rules = RuleSet::base();
+        for name in s.split(',').map(str::trim) {
+            if let Some((_, preset)) = RuleSet::presets().into_iter().find(|(preset, _)| *preset == name) {
+                rules = RuleSet {
+                    buffed_wave: rules.buffed_wave,
+                    max_turns: rules.max_turns,
+                    precedence: rules.precedence,
+                    rulings: rules.rulings,
+                    ..preset
+                };
+            }
+            else if name == "buffed-wave" {
+                rules.buffed_wave = true;
+            }
+            else if rules.rulings.apply(name)? {}
+            else if let Some(max_turns) = name.strip_prefix("max-turns=") {
+                match max_turns.parse() {
+                    Ok(0) | Err(_) => return Err(format!("Invalid turn limit '{}' (expected a positive number)", max_turns)),
+                    Ok(max_turns) => rules.max_turns = Some(max_turns),
+                }
+            }
+            else if let Some(order) = name.strip_prefix("precedence=") {
+                rules.precedence = parse_precedence(order)?;
+            }
+            else {
+                match rules.variant_mut(name) {
+                    Some(enabled) => *enabled = true,
+                    None => {
+                        return Err(format!(
+                            "Unknown rule variant or preset '{}' (expected base, all, max-turns=<n> or a list of {})",
+                            name,
+                            VARIANT_NAMES.join(", "),
+                        ))
+                    }
+                }
+            }
+        }
+        Ok(rules)
+    }
+}
+
+/// Parses an order of all three rock outcomes such as `bad-rock>good-rocks>split-good-rocks`.
+fn parse_precedence(s: &str) -> Result<[OutcomeReason; 3], String> {
+    let order = s.split('>').map(str::parse).collect::<Result<Vec<OutcomeReason>, String>>()?;
+    let is_permutation = order.len() == 3 && DEFAULT_PRECEDENCE.iter().all(|reason| order.contains(reason));
+    if !is_permutation {
+        return Err(format!(
+            "Invalid precedence '{}' (expected bad-rock, good-rocks and split-good-rocks, separated by >)",
+            s,
+        ));
+    }
+    Ok([order[0], order[1], order[2]])
+}
+
+const INITIAL_HAND_DIRECTIONS: [Direction; 2] = [Direction::Left, Direction::Right];
+
+/// A single reversible change made while applying a choice.
+#[derive(Debug, Copy, Clone)]
+enum Change {
+    Space(usize, Space),  // previous contents of a cell
+    SupplyPushed(Color),
+    SupplyRemoved(Color, usize, Token),
+    GraveyardPushed(Color),
+    GraveyardRemoved(Color, usize, Token),
+}
+
+/// Returned by `Game::make` and consumed by `Game::unmake` to take the choice back.
+#[derive(Debug, Clone)]
+pub struct Undo {
+    journal_len: usize,
+    hand_directions: [Direction; 2],
+    turn_state: TurnState,
+    choice_number: u32,
+    hash: u64,
+    history_len: usize,
+}
+
+#[derive(Clone)]
+pub struct Game {
+    pub board: Board,
+    pub supplies: [Vec<Token>; 2],  // [white_supply, black_supply]
+    pub graveyards: [Vec<Token>; 2],  // [white_supply, black_supply]
+    pub hand_directions: [Direction; 2],  // [white_hand, black_hand]
+    pub turn_state: TurnState,
+    pub choice_number: u32,
+    pub rules: RuleSet,
+    journal: Vec<Change>,
+    recording: bool,
+    pub(crate) events: Vec<Event>,
+    pub(crate) logging: bool,
+    hash: u64,
+    history: Vec<u64>,  // hashes after every choice, starting with the initial position
+}
+
+impl Default for Game {
+    fn default() -> Self {
+        Game::new()
+    }
+}
+
+impl Game {
+    pub fn new() -> Self {
+        Game::with_rules(RuleSet::default())
+    }
+
+    pub fn with_rules(rules: RuleSet) -> Self {
+        let mut game = Game {
+            board: Board::new(),
+            supplies: [
+                vec![Token::Scout, Token::Hammer, Token::Hook, Token::Wave, Token::Hand, Token::Bomb],
+                vec![Token::Scout, Token::Hammer, Token::Hook, Token::Wave, Token::Hand, Token::Bomb],
+            ],
+            graveyards: [
+                vec![],
+                vec![],
+            ],
+            hand_directions: INITIAL_HAND_DIRECTIONS,
+            turn_state: TurnState::WhiteFirstAction,
+            choice_number: 0,
+            rules,
+            journal: vec![],
+            recording: false,
+            events: vec![],
+            logging: false,
+            hash: 0,
+            history: vec![],
+        };
+        game.reset_history();
+        game
+    }
+
+    /// Recomputes the hash and forgets the earlier positions, starting repetition counting
+    /// from the current one. Call after changing the position directly.
+    pub fn reset_history(&mut self) {
+        self.hash = self.compute_hash();
+        self.history = vec![self.hash];
+    }
+
+    /// The Zobrist hash of the game state, see [`crate::zobrist`].
+    pub fn hash(&self) -> u64 {
+        self.hash
+    }
+
+    /// How many times the current position has occurred, counting this occurrence.
+    fn repetitions(&self) -> usize {
+        // The turn state advances with every choice, so only every fourth position can match.
+        let key = self.history.last().unwrap();
+        self.history.iter().rev().step_by(4).filter(|&other| other == key).count()
+    }
+
+    /// Applies a choice and returns a record that `unmake` uses to restore the exact state
+    /// from before it. Undo records must be unmade in the reverse order they were made, with
+    /// no other changes to the game in between.
+    pub fn make(&mut self, choice: &Choice) -> Undo {
+        let undo = Undo {
+            journal_len: self.journal.len(),
+            hand_directions: self.hand_directions,
+            turn_state: self.turn_state.clone(),
+            choice_number: self.choice_number,
+            hash: self.hash,
+            history_len: self.history.len(),
+        };
+        let recording = self.recording;
+        self.recording = true;
+        self.apply_choice(choice);
+        self.recording = recording;
+        undo
+    }
+
+    pub fn unmake(&mut self, undo: Undo) {
+        while self.journal.len() > undo.journal_len {
+            match self.journal.pop().unwrap() {
+                Change::Space(cell, space) => self.board.set_cell(cell, space),
+                Change::SupplyPushed(color) => {
+                    self.supplies[color as usize].pop();
+                }
+                Change::SupplyRemoved(color, index, token) => self.supplies[color as usize].insert(index, token),
+                Change::GraveyardPushed(color) => {
+                    self.graveyards[color as usize].pop();
+                }
+                Change::GraveyardRemoved(color, index, token) => self.graveyards[color as usize].insert(index, token),
+            }
+        }
+        self.hand_directions = undo.hand_directions;
+        self.turn_state = undo.turn_state;
+        self.choice_number = undo.choice_number;
+        self.hash = undo.hash;
+        self.history.truncate(undo.history_len);
+    }
+
+    fn record(&mut self, change: Change) {
+        if self.recording {
+            self.journal.push(change);
+        }
+    }
+
+    fn set_space(&mut self, coordinates: Coordinates, space: Space) {
+        self.set_cell(bitboard::cell(coordinates).unwrap(), space);
+    }
+
+    fn set_cell(&mut self, cell: usize, space: Space) {
+        let previous = self.board.cell(cell);
+        self.record(Change::Space(cell, previous));
+        self.hash ^= zobrist::space_key(cell, previous) ^ zobrist::space_key(cell, space);
+        self.board.set_cell(cell, space);
+    }
+
+    fn set_hand_direction(&mut self, color: Color, direction: Direction) {
+        if direction != self.hand_directions[color as usize] {
+            self.emit(Event::HandRotated { color, from: self.hand_directions[color as usize], to: direction });
+        }
+        self.hash ^= zobrist::hand_direction_key(color, self.hand_directions[color as usize]);
+        self.hash ^= zobrist::hand_direction_key(color, direction);
+        self.hand_directions[color as usize] = direction;
+    }
+
+    fn set_turn_state(&mut self, turn_state: TurnState) {
+        if let TurnState::Over(outcome) = turn_state {
+            self.emit(Event::GameEnded(outcome));
+        }
+        self.hash ^= zobrist::turn_state_key(&self.turn_state) ^ zobrist::turn_state_key(&turn_state);
+        self.turn_state = turn_state;
+    }
+
+    fn add_to_supply(&mut self, color: Color, token: Token) {
+        self.supplies[color as usize].push(token);
+        self.hash ^= zobrist::supply_key(color, token);
+        self.record(Change::SupplyPushed(color));
+    }
+
+    fn remove_from_supply(&mut self, color: Color, token: Token) {
+        if let Some(index) = self.supplies[color as usize].iter().position(|&t| t == token) {
+            self.supplies[color as usize].remove(index);
+            self.hash ^= zobrist::supply_key(color, token);
+            self.record(Change::SupplyRemoved(color, index, token));
+        }
+    }
+
+    fn add_to_graveyard(&mut self, color: Color, token: Token) {
+        self.graveyards[color as usize].push(token);
+        self.hash ^= zobrist::graveyard_key(color, token);
+        self.record(Change::GraveyardPushed(color));
+    }
+
+    fn remove_from_graveyard(&mut self, color: Color, token: Token) {
+        if let Some(index) = self.graveyards[color as usize].iter().position(|&t| t == token) {
+            self.graveyards[color as usize].remove(index);
+            self.hash ^= zobrist::graveyard_key(color, token);
+            self.record(Change::GraveyardRemoved(color, index, token));
+        }
+    }
+
+    fn move_to(&mut self, target: Coordinates, destination: Coordinates) {
+        self.set_space(destination, self.board.get_space(target));
+        self.set_space(target, Space::Empty);
+    }
+
+    fn move_cell(&mut self, from: usize, to: usize) {
+        self.set_cell(to, self.board.cell(from));
+        self.set_cell(from, Space::Empty);
+    }
+
+    /// Moves a piece to an empty space other than by pushing it.
+    fn move_piece(&mut self, from: Coordinates, to: Coordinates) {
+        if let Space::Occupied(piece) = self.board.get_space(from) {
+            self.emit(Event::Moved { piece, from, to });
+        }
+        self.move_to(from, to);
+    }
+
+    fn swap(&mut self, target: Coordinates, destination: Coordinates) {
+        let destination_contents = self.board.get_space(destination);
+        self.set_space(destination, self.board.get_space(target));
+        self.set_space(target, destination_contents);
+    }
+
+    /// Removes a token from the board after it fell off the edge or into the pit.
+    fn kill(&mut self, coordinates: Coordinates, color: Color, token: Token) {
+        self.set_space(coordinates, Space::Empty);
+        if self.rules.perma_death {
+            self.add_to_graveyard(color, token);
+        }
+        else {
+            self.add_to_supply(color, token);
+        }
+    }
+
+    /// The space the `color` Hand faces, holding whatever piece is there.
+    pub(crate) fn grabbed_space(&self, color: Color) -> Option<Coordinates> {
+        self.board
+            .find(Piece::Token(color, Token::Hand))
+            .map(|hand_space| hand_space + self.hand_directions[color as usize])
+    }
+
+    /// The spaces whose pieces no effect may move, which depends on the Hand grab ruling.
+    pub(crate) fn held_spaces(&self) -> [Option<Coordinates>; 2] {
+        match self.rules.rulings.hand_grab {
+            HandGrab::Acting => [None, None],
+            HandGrab::Immobile | HandGrab::Carried => [self.grabbed_space(Color::White), self.grabbed_space(Color::Black)],
+        }
+    }
+
+    /// The spaces whose tokens `color` may neither act with nor teleport.
+    pub(crate) fn grabbed_spaces(&self, color: Color) -> [Option<Coordinates>; 2] {
+        match self.rules.rulings.hand_grab {
+            HandGrab::Acting => [self.grabbed_space(color.opposite()), None],
+            HandGrab::Immobile | HandGrab::Carried => self.held_spaces(),
+        }
+    }
+
+    /// The spaces of `held_spaces` or `grabbed_spaces` as a bitboard.
+    fn bitboard_of(spaces: [Option<Coordinates>; 2]) -> Bitboard {
+        spaces.into_iter().flatten().filter_map(bitboard::cell).fold(Bitboard::EMPTY, |board, cell| board | Bitboard::single(cell))
+    }
+
+    pub(crate) fn is_held(&self, coordinates: Coordinates) -> bool {
+        self.held_spaces().contains(&Some(coordinates))
+    }
+
+    /// The space of the piece the `color` Hand at `origin` carries when it moves with its
+    /// ability, under the `carried` grab ruling.
+    pub(crate) fn carried_space(&self, color: Color, origin: Coordinates) -> Option<Coordinates> {
+        let space = origin + self.hand_directions[color as usize];
+        if self.rules.rulings.hand_grab != HandGrab::Carried || space.is_off_board() || self.board.is_empty(space) {
+            return None;
+        }
+        Some(space)
+    }
+
+    /// Whether the piece carried from `carried` can land in `destination` once the Hand has
+    /// left `origin`.
+    pub(crate) fn can_land_carried(&self, carried: Coordinates, origin: Coordinates, destination: Coordinates) -> bool {
+        !destination.is_off_board() && (destination == carried || destination == origin || self.board.is_empty(destination))
+    }
+
+    /// The moves of the buffed Wave at `origin` reflecting the line of pieces in `direction`: the
+    /// unbroken line of pieces starting next to the Wave, each moving to the space as far from
+    /// the Wave on its other side. `None` if there is no line, a landing space is off the board
+    /// or occupied or a piece of the line is held.
+    pub(crate) fn wave_line(&self, origin: Coordinates, direction: Direction) -> Option<Vec<(Coordinates, Coordinates)>> {
+        let mut moves = vec![];
+        let mut target = origin + direction;
+        let mut destination = origin + direction.opposite();
+        while !target.is_off_board() && !self.board.is_empty(target) {
+            if destination.is_off_board() || !self.board.is_empty(destination) || self.is_held(target) {
+                return None;
+            }
+            moves.push((target, destination));
+            target = target + direction;
+            destination = destination + direction.opposite();
+        }
+        if moves.is_empty() {
+            None
+        }
+        else {
+            Some(moves)
+        }
+    }
+
+    fn push(&mut self, target: Coordinates, direction: Direction, distance: usize) {
+        if self.is_held(target) {
+            return;
+        }
+        let from = bitboard::cell(target).unwrap();
+        if self.board.is_empty_cell(from) {
+            panic!("Attempted to push empty space");
+        }
+        // The piece slides until the next piece in its way, or off the edge if there is none
+        let ray = bitboard::ray(from, direction);
+        let (room, blocked) = match (ray & self.board.occupied()).nearest(direction) {
+            Some(blocker) => (target.distance(bitboard::coordinates(blocker)) - 1, true),
+            None => (ray.count() as usize, false),
+        };
+        let to = bitboard::cell_at_distance(from, direction, distance.min(room)).unwrap();
+        if to != from {
+            self.move_cell(from, to);
+        }
+        let destination = bitboard::coordinates(to);
+        if distance > room && !blocked {
+            if let Space::Occupied(Piece::Token(color, token)) = self.board.cell(to) {
+                self.emit_pushed(target, destination);
+                self.emit(Event::KilledOffEdge { color, token, from: destination });
+                self.kill(destination, color, token);
+                return;
+            }
+        }
+        self.emit_pushed(target, destination);
+    }
+
+    fn emit_pushed(&mut self, from: Coordinates, to: Coordinates) {
+        if let (true, Space::Occupied(piece)) = (self.logging && from != to, self.board.get_space(to)) {
+            self.emit(Event::Pushed { piece, from, to, distance: from.distance(to) });
+        }
+    }
+
+    fn cascading_push(&mut self, target: Coordinates, direction: Direction, distance: usize) {
+        if !target.is_off_board() && !self.board.is_empty(target) {
+            let next_position = target + direction;
+            self.cascading_push(next_position, direction, distance);
+
+            self.push(target, direction, distance);
+        }
+    }
+}
+
+impl MctsGame for Game {
+    type Choice = Choice;
+
+    type PlayerId = Color;
+
+    fn get_all_choices(&self) -> Vec<Self::Choice> {
+        let mut choices = Vec::with_capacity(32);
+        choices.push(Choice::Pass);
+        match self.turn_state.get_matchable() {
+            Some((color, used_piece)) => {
+                let grabbed = Game::bitboard_of(self.grabbed_spaces(color));
+                let held = Game::bitboard_of(self.held_spaces());
+                let occupied = self.board.occupied();
+                // Pieces that effects may move
+                let movable = occupied & !held;
+
+                // Use token
+                for cell in self.board.tokens(color).cells() {
+                    let token = match self.board.cell(cell) {
+                        Space::Occupied(Piece::Token(_, token)) => token,
+                        _ => continue,
+                    };
+                    if used_piece == Some(token) {
+                        continue
+                    }
+
+                    if grabbed.contains(cell) {
+                        continue;
+                    }
+                    let coordinates = bitboard::coordinates(cell);
+
+                    // Hand's move is implement as an ability
+                    if token != Token::Hand {
+                        // Basic move
+                        for direction in Direction::iter() {
+                            match bitboard::neighbor(cell, direction) {
+                                None => {
+                                    if self.rules.rulings.step_off_edge {
+                                        choices.push(Choice::Move(token, direction));
+                                    }
+                                }
+                                Some(destination) => {
+                                    if !occupied.contains(destination) {
+                                        choices.push(Choice::Move(token, direction));
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    // Abilities
+                    match token {
+                        Token::Daimyo => {
+                            let destinations: Vec<Coordinates> = bitboard::neighbors(cell)
+                                .filter(|&(_, destination)| !occupied.contains(destination))
+                                .map(|(_, destination)| bitboard::coordinates(destination))
+                                .collect();
+                            for target_cell in self.board.tokens(color).cells() {
+                                let other_token = match self.board.cell(target_cell) {
+                                    Space::Occupied(Piece::Token(_, other_token)) => other_token,
+                                    _ => continue,
+                                };
+                                if other_token == Token::Daimyo && !self.rules.rulings.daimyo_self_teleport {
+                                    continue;
+                                }
+                                if grabbed.contains(target_cell) {
+                                    continue;
+                                }
+                                let target = bitboard::coordinates(target_cell);
+                                for &destination in destinations.iter() {
+                                    if other_token == Token::Hand && self.rules.rulings.daimyo_turns_hand {
+                                        for hand_direction in Direction::iter() {
+                                            choices.push(Choice::UseAbility(Ability::Daimyo {
+                                                target,
+                                                destination,
+                                                hand_direction: Some(hand_direction),
+                                            }));
+                                        }
+                                    }
+                                    else {
+                                        choices.push(Choice::UseAbility(Ability::Daimyo {
+                                            target,
+                                            destination,
+                                            hand_direction: None,
+                                        }));
+                                    }
+                                }
+                            }
+                        },
+                        Token::Scout => {
+                            for direction in Direction::iter() {
+                                for distance in 1..=2 {
+                                    if let Some(destination) = bitboard::cell_at_distance(cell, direction, distance) {
+                                        if movable.contains(destination) {
+                                            let destination = bitboard::coordinates(destination);
+                                            choices.push(Choice::UseAbility(Ability::Scout{target: coordinates, destination}));
+                                        }
+                                    }
+                                }
+                            }
+                        },
+                        Token::Hammer => {
+                            for (direction, target) in bitboard::neighbors(cell) {
+                                if movable.contains(target) {
+                                    let target = bitboard::coordinates(target);
+                                    for distance in 1..=3 {
+                                        choices.push(Choice::UseAbility(Ability::Hammer{target, direction, distance}));
+                                    }
+                                }
+                            }
+                        },
+                        Token::Hook => {
+                            for direction in Direction::iter() {
+                                for distance_to_target in 2..=4 {
+                                    if let Some(target) = bitboard::cell_at_distance(cell, direction, distance_to_target) {
+                                        if movable.contains(target) {
+                                            let target = bitboard::coordinates(target);
+                                            for distance_to_pull in 1..distance_to_target {
+                                                choices.push(Choice::UseAbility(Ability::Hook {
+                                                    target,
+                                                    direction: direction.opposite(),
+                                                    distance: distance_to_pull,
+                                                }));
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        },
+                        Token::Wave => {
+                            for direction in Direction::iter() {
+                                if self.rules.buffed_wave {
+                                    if self.wave_line(coordinates, direction).is_some() {
+                                        choices.push(Choice::UseAbility(Ability::BuffedWave { origin: coordinates, direction }));
+                                    }
+                                    continue;
+                                }
+                                let target = bitboard::neighbor(cell, direction);
+                                let destination = bitboard::neighbor(cell, direction.opposite());
+                                if let (Some(target), Some(destination)) = (target, destination) {
+                                    if movable.contains(target) && !occupied.contains(destination) {
+                                        choices.push(Choice::UseAbility(Ability::Wave {
+                                            target: bitboard::coordinates(target),
+                                            destination: bitboard::coordinates(destination),
+                                        }));
+                                    }
+                                }
+                            }
+                        },
+                        Token::Hand => {
+                            let carried_space = self.carried_space(color, coordinates);
+                            for (move_direction, new_cell) in bitboard::neighbors(cell) {
+                                if !occupied.contains(new_cell) {
+                                    let new_coordinates = bitboard::coordinates(new_cell);
+                                    for hand_direction in Direction::iter() {
+                                        if let Some(carried) = carried_space {
+                                            if !self.can_land_carried(carried, coordinates, new_coordinates + hand_direction) {
+                                                continue;
+                                            }
+                                        }
+                                        choices.push(Choice::UseAbility(Ability::Hand{origin: coordinates, move_direction, hand_direction}));
+                                    }
+                                }
+                            }
+                        },
+                        Token::Bomb => {
+                            choices.push(Choice::UseAbility(Ability::Bomb{ origin: coordinates }));
+                        },
+                    }
+                }
+
+                // Deploy
+                if self.board.gate_is_empty(color) {
+                    for token in self.supplies[color as usize].iter() {
+                        if *token == Token::Hand && self.rules.rulings.hand_deploy == HandDeployOrientation::Chosen {
+                            for direction in Direction::iter() {
+                                choices.push(Choice::Deploy(*token, Some(direction)));
+                            }
+                        }
+                        else {
+                            choices.push(Choice::Deploy(*token, None));
+                        }
+                    }
+                }
+
+                // Revive
+                if self.rules.revive_action {
+                    for token in self.graveyards[color as usize].iter() {
+                        choices.push(Choice::Revive(*token));
+                    }
+                }
+            },
+            None => {},
+        }
+        choices
+    }
+
+    fn apply_choice(&mut self, choice: &Self::Choice) {
+        let mut used_piece = None;
+        match self.turn_state.get_matchable() {
+            Some((color, _)) => {
+                match choice {
+                    Choice::Pass => {},
+                    Choice::Deploy(token, direction) => {
+                        self.remove_from_supply(color, *token);
+                        self.set_space(self.board.gate_coords(color), Space::Occupied(Piece::Token(color, *token)));
+                        self.emit(Event::Deployed { color, token: *token, to: self.board.gate_coords(color) });
+                        if let Some(direction) = direction {
+                            self.set_hand_direction(color, *direction);
+                        }
+                        else if *token == Token::Hand && self.rules.rulings.hand_deploy == HandDeployOrientation::Initial {
+                            self.set_hand_direction(color, INITIAL_HAND_DIRECTIONS[color as usize]);
+                        }
+                    },
+                    Choice::Move(token, direction) => {
+                        let coordinates = self.board.find(Piece::Token(color, *token)).unwrap();
+                        if (coordinates + *direction).is_off_board() {
+                            self.emit(Event::KilledOffEdge { color, token: *token, from: coordinates });
+                            self.kill(coordinates, color, *token);
+                        }
+                        else {
+                            self.move_piece(coordinates, coordinates + *direction);
+                        }
+                        used_piece = Some(*token);
+                    },
+                    Choice::UseAbility(ability) => {
+                        match ability {
+                            Ability::Hammer { target, direction, distance } => {
+                                used_piece = Some(Token::Hammer);
+                                self.push(*target, *direction, *distance);
+                            }
+                            Ability::Wave { target, destination } => {
+                                used_piece = Some(Token::Wave);
+                                self.move_piece(*target, *destination);
+                            }
+                            Ability::BuffedWave { origin, direction } => {
+                                used_piece = Some(Token::Wave);
+                                for (target, destination) in self.wave_line(*origin, *direction).unwrap_or_default() {
+                                    self.move_piece(target, destination);
+                                }
+                            }
+                            Ability::Scout { target, destination } => {
+                                used_piece = Some(Token::Scout);
+                                if let (Space::Occupied(piece), Space::Occupied(with)) = (self.board.get_space(*target), self.board.get_space(*destination)) {
+                                    self.emit(Event::Swapped { piece, with, from: *target, to: *destination });
+                                }
+                                self.swap(*target, *destination);
+                            }
+                            Ability::Daimyo { target, destination, hand_direction } => {
+                                used_piece = Some(Token::Daimyo);
+                                self.move_piece(*target, *destination);
+                                if let Some(hand_direction) = hand_direction {
+                                    self.set_hand_direction(color, *hand_direction);
+                                }
+                            }
+                            Ability::Hook { target, direction, distance } => {
+                                used_piece = Some(Token::Hook);
+                                self.push(*target, *direction, *distance);
+                            }
+                            Ability::Bomb { origin } => {
+                                used_piece = Some(Token::Bomb);
+                                for direction in Direction::iter() {
+                                    self.cascading_push(*origin + direction, direction, 1);
+                                }
+                            }
+                            Ability::Hand { origin, move_direction, hand_direction } => {
+                                used_piece = Some(Token::Hand);
+                                let destination = *origin + *move_direction;
+                                match self.carried_space(color, *origin) {
+                                    Some(carried) => {
+                                        let space = self.board.get_space(carried);
+                                        self.set_space(carried, Space::Empty);
+                                        self.move_piece(*origin, destination);
+                                        self.set_space(destination + *hand_direction, space);
+                                        if let Space::Occupied(piece) = space {
+                                            self.emit(Event::Moved { piece, from: carried, to: destination + *hand_direction });
+                                        }
+                                    }
+                                    None => self.move_piece(*origin, destination),
+                                }
+                                self.set_hand_direction(color, *hand_direction);
+                            }
+                        }
+                    }
+                    Choice::Revive(token) => {
+                        self.emit(Event::Revived { color, token: *token });
+                        self.add_to_supply(color, *token);
+                        self.remove_from_graveyard(color, *token);
+                    }
+                }
+            }
+            None => {},
+        }
+
+        if self.rules.pit_of_misfortune {
+            match self.board.get_space(CENTER_SPACE) {
+                Space::Occupied(Piece::Token(color, token)) => {
+                    self.emit(Event::KilledInPit { color, token });
+                    self.kill(CENTER_SPACE, color, token);
+                },
+                _ => {},
+            }
+        }
+
+        if !self.is_terminal() {
+            if let Some(outcome) = self.rock_outcome() {
+                self.set_turn_state(TurnState::Over(outcome));
+            }
+        }
+
+        match self.turn_state {
+            TurnState::WhiteFirstAction => self.set_turn_state(TurnState::WhiteSecondAction { used_piece, }),
+            TurnState::WhiteSecondAction {..} => self.set_turn_state(TurnState::BlackFirstAction),
+            TurnState::BlackFirstAction => self.set_turn_state(TurnState::BlackSecondAction { used_piece, }),
+            TurnState::BlackSecondAction {..} => self.set_turn_state(TurnState::WhiteFirstAction),
+            TurnState::Over(_) => {},
+        }
+
+        self.choice_number += 1;
+
+        self.history.push(self.hash);
+        if !self.is_terminal() {
+            if self.rules.draw_by_repetition && self.repetitions() >= 3 {
+                self.set_turn_state(TurnState::Over(GameOutcome { winner: None, reason: OutcomeReason::Repetition }));
+            }
+            else if self.rules.max_turns.is_some_and(|max_turns| self.choice_number >= 2 * max_turns) {
+                self.set_turn_state(TurnState::Over(GameOutcome { winner: None, reason: OutcomeReason::TurnLimit }));
+            }
+        }
+    }
+
+    fn get_active_player_id(&self) -> Self::PlayerId {
+        match self.turn_state {
+            TurnState::WhiteFirstAction | TurnState::WhiteSecondAction {..} => Color::White,
+            TurnState::BlackFirstAction | TurnState::BlackSecondAction {..} => Color::Black,
+            TurnState::Over(_) => panic!("Attempted to get active player in terminal game"),
+        }
+    }
+
+    fn is_terminal(&self) -> bool {
+        match self.turn_state {
+            TurnState::WhiteFirstAction
+                | TurnState::WhiteSecondAction {..}
+                | TurnState::BlackFirstAction
+                | TurnState::BlackSecondAction {..} => false,
+            TurnState::Over(_) => true,
+        }
+    }
+
+    fn reward_for(&self, color: Color) -> f64 {
+        self.evaluate(color)
+    }
+
+    fn position_key(&self) -> u64 {
+        // The same position draws sooner at a later choice or after more repetitions
+        let progress = (self.choice_number as u64) << 8 | self.repetitions() as u64;
+        self.hash ^ progress.wrapping_mul(0x9E37_79B9_7F4A_7C15)
+    }
+}
+
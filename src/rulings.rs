@@ -0,0 +1,151 @@
+//! Rulings on questions the printed rules leave open. The defaults are the interpretation the
+//! engine has always played; each ruling can be changed through the rule set string, e.g.
+//! `all,step-off-edge,hand-deploy=kept`.
+
+use std::fmt;
+use std::str::FromStr;
+
+/// Which way the Hand faces when it is deployed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum HandDeployOrientation {
+    /// The player chooses a direction as part of the deploy.
+    Chosen,
+    /// The direction it faced at the start of the game.
+    Initial,
+    /// The direction it faced when it last left the board.
+    Kept,
+}
+
+impl fmt::Display for HandDeployOrientation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HandDeployOrientation::Chosen => write!(f, "chosen"),
+            HandDeployOrientation::Initial => write!(f, "initial"),
+            HandDeployOrientation::Kept => write!(f, "kept"),
+        }
+    }
+}
+
+impl FromStr for HandDeployOrientation {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "chosen" => Ok(HandDeployOrientation::Chosen),
+            "initial" => Ok(HandDeployOrientation::Initial),
+            "kept" => Ok(HandDeployOrientation::Kept),
+            _ => Err(format!("Unknown Hand deploy orientation '{}' (expected chosen, initial or kept)", s)),
+        }
+    }
+}
+
+/// What the Hand's grab does to the piece in the space the Hand faces.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum HandGrab {
+    /// An opposing token in the grabbed space cannot act, but other effects still move it.
+    Acting,
+    /// The grabbed piece, whoever owns it, cannot act and no effect moves it.
+    Immobile,
+    /// As `Immobile`, and when the Hand moves with its ability the piece goes along, landing in
+    /// the space the Hand faces after the move. The Hand cannot move where the piece has nowhere
+    /// to land.
+    Carried,
+}
+
+impl fmt::Display for HandGrab {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HandGrab::Acting => write!(f, "acting"),
+            HandGrab::Immobile => write!(f, "immobile"),
+            HandGrab::Carried => write!(f, "carried"),
+        }
+    }
+}
+
+impl FromStr for HandGrab {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "acting" => Ok(HandGrab::Acting),
+            "immobile" => Ok(HandGrab::Immobile),
+            "carried" => Ok(HandGrab::Carried),
+            _ => Err(format!("Unknown Hand grab ruling '{}' (expected acting, immobile or carried)", s)),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Rulings {
+    /// A token may make a basic move off the edge of the board, removing itself from play. The
+    /// Hand moves with its ability and never steps off.
+    pub step_off_edge: bool,
+    /// The Daimyo may use its ability on itself, teleporting to a space next to itself.
+    pub daimyo_self_teleport: bool,
+    pub hand_deploy: HandDeployOrientation,
+    /// Teleporting your own Hand with the Daimyo also chooses the direction the Hand faces.
+    pub daimyo_turns_hand: bool,
+    pub hand_grab: HandGrab,
+}
+
+const FLAG_NAMES: [&str; 3] = ["step-off-edge", "daimyo-self-teleport", "daimyo-turns-hand"];
+
+impl Rulings {
+    fn flag_mut(&mut self, name: &str) -> Option<&mut bool> {
+        match name {
+            "step-off-edge" => Some(&mut self.step_off_edge),
+            "daimyo-self-teleport" => Some(&mut self.daimyo_self_teleport),
+            "daimyo-turns-hand" => Some(&mut self.daimyo_turns_hand),
+            _ => None,
+        }
+    }
+
+    /// Applies one item of a rule set string, returning whether it was a ruling at all.
+    pub(crate) fn apply(&mut self, item: &str) -> Result<bool, String> {
+        if let Some(orientation) = item.strip_prefix("hand-deploy=") {
+            self.hand_deploy = orientation.parse()?;
+            return Ok(true);
+        }
+        if let Some(grab) = item.strip_prefix("hand-grab=") {
+            self.hand_grab = grab.parse()?;
+            return Ok(true);
+        }
+        match self.flag_mut(item) {
+            Some(enabled) => {
+                *enabled = true;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// The rule set string items for the rulings that differ from the defaults.
+    pub(crate) fn items(&self) -> Vec<String> {
+        let flags = [self.step_off_edge, self.daimyo_self_teleport, self.daimyo_turns_hand];
+        let mut items: Vec<String> = FLAG_NAMES
+            .into_iter()
+            .zip(flags)
+            .filter(|(_, enabled)| *enabled)
+            .map(|(name, _)| name.to_string())
+            .collect();
+        if self.hand_deploy != Rulings::default().hand_deploy {
+            items.push(format!("hand-deploy={}", self.hand_deploy));
+        }
+        if self.hand_grab != Rulings::default().hand_grab {
+            items.push(format!("hand-grab={}", self.hand_grab));
+        }
+        items
+    }
+}
+
+impl Default for Rulings {
+    fn default() -> Self {
+        Rulings {
+            step_off_edge: false,
+            daimyo_self_teleport: false,
+            hand_deploy: HandDeployOrientation::Chosen,
+            daimyo_turns_hand: false,
+            hand_grab: HandGrab::Acting,
+        }
+    }
+}
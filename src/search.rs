@@ -0,0 +1,137 @@
+//! Choosing a choice with the MCTS within a budget of time and iterations.
+
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use crate::board::Color;
+use crate::choices::Choice;
+use crate::mcts::Game as MctsGame;
+use crate::mcts::{Mcts, MctsSettings, SearchResult};
+use crate::rollout::RolloutWeights;
+use crate::rules::{Game, TurnState};
+
+/// The iterations searched for a choice when the budget sets no limit.
+pub const DEFAULT_ITERATIONS: usize = 1000000;
+
+/// How long the search may think. Every limit that is set applies and the search stops at
+/// the first one reached. A turn is both actions of one player, so the turn limit is shared
+/// between them.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SearchBudget {
+    pub per_action: Option<Duration>,
+    pub per_turn: Option<Duration>,
+    /// The most iterations for one action.
+    pub max_iterations: Option<usize>,
+}
+
+impl SearchBudget {
+    pub fn iterations(iterations: usize) -> Self {
+        SearchBudget { per_action: None, per_turn: None, max_iterations: Some(iterations) }
+    }
+
+    pub fn per_action(time: Duration) -> Self {
+        SearchBudget { per_action: Some(time), per_turn: None, max_iterations: None }
+    }
+
+    pub fn per_turn(time: Duration) -> Self {
+        SearchBudget { per_action: None, per_turn: Some(time), max_iterations: None }
+    }
+
+    fn has_time_limit(&self) -> bool {
+        self.per_action.is_some() || self.per_turn.is_some()
+    }
+}
+
+impl Default for SearchBudget {
+    fn default() -> Self {
+        SearchBudget::iterations(DEFAULT_ITERATIONS)
+    }
+}
+
+impl fmt::Display for SearchBudget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut limits = vec![];
+        if let Some(time) = self.per_action {
+            limits.push(format!("{}s per action", time.as_secs_f64()));
+        }
+        if let Some(time) = self.per_turn {
+            limits.push(format!("{}s per turn", time.as_secs_f64()));
+        }
+        if let Some(iterations) = self.max_iterations {
+            limits.push(format!("{} iterations", iterations));
+        }
+        if limits.is_empty() {
+            limits.push(String::from("unlimited"));
+        }
+        write!(f, "{}", limits.join(", "))
+    }
+}
+
+/// The start of the turn the searcher is playing, to share the turn budget between its actions.
+#[derive(Debug, Copy, Clone)]
+struct TurnClock {
+    color: Color,
+    /// The choice number of the first action of the turn.
+    first_action: u32,
+    started: Instant,
+}
+
+/// Searches for the choices of one player, keeping the time used in the current turn and
+/// the tree of the last search.
+pub struct Searcher {
+    pub budget: SearchBudget,
+    mcts: Mcts<Game, RolloutWeights>,
+    clock: Option<TurnClock>,
+}
+
+impl Searcher {
+    pub fn new(budget: SearchBudget) -> Self {
+        Searcher::with_settings(budget, MctsSettings::default(), RolloutWeights::default())
+    }
+
+    pub fn with_settings(budget: SearchBudget, settings: MctsSettings, rollout: RolloutWeights) -> Self {
+        Searcher { budget, mcts: Mcts::with_policy(settings, rollout), clock: None }
+    }
+
+    pub fn rollout(&self) -> RolloutWeights {
+        self.mcts.policy
+    }
+
+    /// The time this action may take: what remains of the action budget and of the turn
+    /// budget, where the first action of a turn leaves half of the turn for the second.
+    fn allowance(&mut self, game: &Game) -> Option<Duration> {
+        let per_turn = self.budget.per_turn.map(|per_turn| {
+            let color = game.get_active_player_id();
+            let first = matches!(game.turn_state, TurnState::WhiteFirstAction | TurnState::BlackFirstAction);
+            // The second action keeps the clock of the first, just before it
+            let clock = match self.clock {
+                Some(clock) if !first && clock.color == color && clock.first_action + 1 == game.choice_number => clock,
+                _ => TurnClock { color, first_action: game.choice_number, started: Instant::now() },
+            };
+            self.clock = Some(clock);
+            let remaining = per_turn.saturating_sub(clock.started.elapsed());
+            if first { remaining / 2 } else { remaining }
+        });
+        match (self.budget.per_action, per_turn) {
+            (Some(per_action), Some(per_turn)) => Some(per_action.min(per_turn)),
+            (per_action, per_turn) => per_action.or(per_turn),
+        }
+    }
+
+    /// Searches `game`, which must not be over, within the budget, returning the statistics of
+    /// every choice.
+    pub fn search(&mut self, game: &Game) -> SearchResult<Choice> {
+        let max_iterations = match self.budget.max_iterations {
+            Some(iterations) => iterations.max(1),
+            None if self.budget.has_time_limit() => usize::MAX,
+            None => DEFAULT_ITERATIONS,
+        };
+        let deadline = self.allowance(game).map(|allowance| Instant::now() + allowance);
+        self.mcts.search(game, max_iterations, deadline)
+    }
+
+    /// Searches `game` within the budget and returns the best choice found.
+    pub fn choose(&mut self, game: &Game) -> Choice {
+        self.search(game).choice
+    }
+}
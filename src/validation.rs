@@ -0,0 +1,275 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::mcts::Game as MctsGame;
+use strum::IntoEnumIterator;
+
+use crate::board::{Color, Piece, Space, Token};
+use crate::choices::{Ability, Choice};
+use crate::coordinates::{Coordinates, Direction};
+use crate::rules::Game;
+use crate::rulings::HandDeployOrientation;
+
+/// Why a choice cannot be applied to the current game state.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum IllegalMove {
+    GameOver,
+    /// The piece at these coordinates is not the active player's token needed for the choice.
+    NotYourToken(Coordinates),
+    TokenNotOnBoard(Token),
+    /// The token is held by a Hand and cannot act.
+    TokenGrabbed(Token),
+    TokenAlreadyUsed(Token),
+    NotInSupply(Token),
+    NotInGraveyard(Token),
+    GateOccupied,
+    ReviveNotAllowed,
+    HandDirectionRequired,
+    UnexpectedHandDirection,
+    /// The Hand moves with its ability rather than a basic move.
+    HandMovesWithAbility,
+    DaimyoCannotTeleportItself,
+    /// The Wave ability used is not the one the rules call for.
+    WrongWave,
+    /// A piece of the buffed Wave's line has no empty space to land on.
+    WaveLineBlocked,
+    /// The piece at these coordinates is held by a Hand and cannot be moved.
+    PieceHeld(Coordinates),
+    /// The piece the Hand carries has no empty space to land on.
+    CarriedPieceBlocked,
+    OffBoard(Coordinates),
+    DestinationOccupied(Coordinates),
+    EmptyTarget(Coordinates),
+    OutOfRange(Coordinates),
+    InvalidDistance(usize),
+}
+
+impl fmt::Display for IllegalMove {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IllegalMove::GameOver => write!(f, "The game is over"),
+            IllegalMove::NotYourToken(coordinates) => write!(f, "{} does not hold the token that would act", coordinates),
+            IllegalMove::TokenNotOnBoard(token) => write!(f, "Your {:?} is not on the board", token),
+            IllegalMove::TokenGrabbed(token) => write!(f, "Your {:?} is held by a Hand", token),
+            IllegalMove::TokenAlreadyUsed(token) => write!(f, "Your {:?} was already used this turn", token),
+            IllegalMove::NotInSupply(token) => write!(f, "Your {:?} is not in your supply", token),
+            IllegalMove::NotInGraveyard(token) => write!(f, "Your {:?} is not in your graveyard", token),
+            IllegalMove::GateOccupied => write!(f, "Your gate is occupied"),
+            IllegalMove::ReviveNotAllowed => write!(f, "The revive action is not part of these rules"),
+            IllegalMove::HandDirectionRequired => write!(f, "The choice must give the direction the Hand faces"),
+            IllegalMove::UnexpectedHandDirection => write!(f, "The choice does not turn a Hand"),
+            IllegalMove::HandMovesWithAbility => write!(f, "The Hand moves with its ability"),
+            IllegalMove::DaimyoCannotTeleportItself => write!(f, "The Daimyo cannot teleport itself"),
+            IllegalMove::WrongWave => write!(f, "These rules use the other version of the Wave"),
+            IllegalMove::WaveLineBlocked => write!(f, "The line cannot land on the other side of the Wave"),
+            IllegalMove::PieceHeld(coordinates) => write!(f, "The piece at {} is held by a Hand", coordinates),
+            IllegalMove::CarriedPieceBlocked => write!(f, "The piece the Hand carries cannot land"),
+            IllegalMove::OffBoard(coordinates) => write!(f, "{:?} is off the board", coordinates),
+            IllegalMove::DestinationOccupied(coordinates) => write!(f, "{} is occupied", coordinates),
+            IllegalMove::EmptyTarget(coordinates) => write!(f, "There is nothing at {}", coordinates),
+            IllegalMove::OutOfRange(coordinates) => write!(f, "{} is out of range", coordinates),
+            IllegalMove::InvalidDistance(distance) => write!(f, "{} is not a valid distance", distance),
+        }
+    }
+}
+
+impl Error for IllegalMove {}
+
+/// Checks that a Hand direction is given exactly when the choice turns the Hand.
+fn check_hand_direction(turns_hand: bool, hand_direction: &Option<Direction>) -> Result<(), IllegalMove> {
+    match (turns_hand, hand_direction) {
+        (true, None) => Err(IllegalMove::HandDirectionRequired),
+        (false, Some(_)) => Err(IllegalMove::UnexpectedHandDirection),
+        _ => Ok(()),
+    }
+}
+
+impl Game {
+    /// Checks that `choice` is one of `get_all_choices` and explains why not otherwise.
+    pub fn validate_choice(&self, choice: &Choice) -> Result<(), IllegalMove> {
+        let (color, used_piece) = self.turn_state.get_matchable().ok_or(IllegalMove::GameOver)?;
+        match choice {
+            Choice::Pass => Ok(()),
+            Choice::Deploy(token, direction) => {
+                if !self.supplies[color as usize].contains(token) {
+                    return Err(IllegalMove::NotInSupply(*token));
+                }
+                if !self.board.gate_is_empty(color) {
+                    return Err(IllegalMove::GateOccupied);
+                }
+                let chooses_direction = *token == Token::Hand && self.rules.rulings.hand_deploy == HandDeployOrientation::Chosen;
+                check_hand_direction(chooses_direction, direction)
+            }
+            Choice::Move(token, direction) => {
+                let coordinates = self.active_token(color, used_piece, *token)?;
+                if *token == Token::Hand {
+                    return Err(IllegalMove::HandMovesWithAbility);
+                }
+                let destination = coordinates + *direction;
+                if destination.is_off_board() && self.rules.rulings.step_off_edge {
+                    return Ok(());
+                }
+                self.check_empty_destination(destination)
+            }
+            Choice::Revive(token) => {
+                if !self.rules.revive_action {
+                    return Err(IllegalMove::ReviveNotAllowed);
+                }
+                if !self.graveyards[color as usize].contains(token) {
+                    return Err(IllegalMove::NotInGraveyard(*token));
+                }
+                Ok(())
+            }
+            Choice::UseAbility(ability) => self.validate_ability(color, used_piece, ability),
+        }
+    }
+
+    /// Validates `choice` and applies it only if it is legal.
+    pub fn try_apply_choice(&mut self, choice: &Choice) -> Result<(), IllegalMove> {
+        self.validate_choice(choice)?;
+        self.apply_choice(choice);
+        Ok(())
+    }
+
+    /// Finds a token the active player may act with this action.
+    fn active_token(&self, color: Color, used_piece: Option<Token>, token: Token) -> Result<Coordinates, IllegalMove> {
+        let coordinates = self.board.find(Piece::Token(color, token)).ok_or(IllegalMove::TokenNotOnBoard(token))?;
+        if used_piece == Some(token) {
+            return Err(IllegalMove::TokenAlreadyUsed(token));
+        }
+        if self.grabbed_spaces(color).contains(&Some(coordinates)) {
+            return Err(IllegalMove::TokenGrabbed(token));
+        }
+        Ok(coordinates)
+    }
+
+    /// Finds the acting token of an ability, which must be at `coordinates`.
+    fn acting_token(&self, color: Color, used_piece: Option<Token>, token: Token, coordinates: Coordinates) -> Result<(), IllegalMove> {
+        if coordinates.is_off_board() {
+            return Err(IllegalMove::OffBoard(coordinates));
+        }
+        if self.board.get_space(coordinates) != Space::Occupied(Piece::Token(color, token)) {
+            return Err(IllegalMove::NotYourToken(coordinates));
+        }
+        self.active_token(color, used_piece, token).map(|_| ())
+    }
+
+    fn check_empty_destination(&self, destination: Coordinates) -> Result<(), IllegalMove> {
+        if destination.is_off_board() {
+            return Err(IllegalMove::OffBoard(destination));
+        }
+        if !self.board.is_empty(destination) {
+            return Err(IllegalMove::DestinationOccupied(destination));
+        }
+        Ok(())
+    }
+
+    /// Checks that `target` holds a piece the ability may move.
+    fn check_occupied_target(&self, target: Coordinates) -> Result<(), IllegalMove> {
+        if target.is_off_board() {
+            return Err(IllegalMove::OffBoard(target));
+        }
+        if self.board.is_empty(target) {
+            return Err(IllegalMove::EmptyTarget(target));
+        }
+        if self.is_held(target) {
+            return Err(IllegalMove::PieceHeld(target));
+        }
+        Ok(())
+    }
+
+    fn validate_ability(&self, color: Color, used_piece: Option<Token>, ability: &Ability) -> Result<(), IllegalMove> {
+        match *ability {
+            Ability::Hammer { target, direction, distance } => {
+                let hammer = self.active_token(color, used_piece, Token::Hammer)?;
+                if target != hammer + direction {
+                    return Err(IllegalMove::OutOfRange(target));
+                }
+                self.check_occupied_target(target)?;
+                if !(1..=3).contains(&distance) {
+                    return Err(IllegalMove::InvalidDistance(distance));
+                }
+                Ok(())
+            }
+            Ability::Wave { target, destination } => {
+                if self.rules.buffed_wave {
+                    return Err(IllegalMove::WrongWave);
+                }
+                let wave = self.active_token(color, used_piece, Token::Wave)?;
+                let direction = Direction::iter()
+                    .find(|&direction| wave + direction == target)
+                    .ok_or(IllegalMove::OutOfRange(target))?;
+                if destination != wave + direction.opposite() {
+                    return Err(IllegalMove::OutOfRange(destination));
+                }
+                self.check_occupied_target(target)?;
+                self.check_empty_destination(destination)
+            }
+            Ability::BuffedWave { origin, direction } => {
+                if !self.rules.buffed_wave {
+                    return Err(IllegalMove::WrongWave);
+                }
+                self.acting_token(color, used_piece, Token::Wave, origin)?;
+                self.check_occupied_target(origin + direction)?;
+                self.wave_line(origin, direction).map(|_| ()).ok_or(IllegalMove::WaveLineBlocked)
+            }
+            Ability::Scout { target, destination } => {
+                self.acting_token(color, used_piece, Token::Scout, target)?;
+                let in_range = Direction::iter()
+                    .any(|direction| destination == target + direction || destination == target + direction + direction);
+                if !in_range {
+                    return Err(IllegalMove::OutOfRange(destination));
+                }
+                self.check_occupied_target(destination)
+            }
+            Ability::Daimyo { target, destination, hand_direction } => {
+                let daimyo = self.active_token(color, used_piece, Token::Daimyo)?;
+                if target == daimyo && !self.rules.rulings.daimyo_self_teleport {
+                    return Err(IllegalMove::DaimyoCannotTeleportItself);
+                }
+                if target.is_off_board() {
+                    return Err(IllegalMove::OffBoard(target));
+                }
+                let target_token = match self.board.get_space(target) {
+                    Space::Occupied(Piece::Token(target_color, token)) if target_color == color => {
+                        if self.grabbed_spaces(color).contains(&Some(target)) {
+                            return Err(IllegalMove::TokenGrabbed(token));
+                        }
+                        token
+                    }
+                    Space::Empty => return Err(IllegalMove::EmptyTarget(target)),
+                    _ => return Err(IllegalMove::NotYourToken(target)),
+                };
+                if !Direction::iter().any(|direction| destination == daimyo + direction) {
+                    return Err(IllegalMove::OutOfRange(destination));
+                }
+                self.check_empty_destination(destination)?;
+                let turns_hand = target_token == Token::Hand && self.rules.rulings.daimyo_turns_hand;
+                check_hand_direction(turns_hand, &hand_direction)
+            }
+            Ability::Hook { target, direction, distance } => {
+                let hook = self.active_token(color, used_piece, Token::Hook)?;
+                let distance_to_target = (2..=4)
+                    .find(|&steps| (0..steps).fold(hook, |position, _| position + direction.opposite()) == target)
+                    .ok_or(IllegalMove::OutOfRange(target))?;
+                self.check_occupied_target(target)?;
+                if distance == 0 || distance >= distance_to_target {
+                    return Err(IllegalMove::InvalidDistance(distance));
+                }
+                Ok(())
+            }
+            Ability::Bomb { origin } => self.acting_token(color, used_piece, Token::Bomb, origin),
+            Ability::Hand { origin, move_direction, hand_direction } => {
+                self.acting_token(color, used_piece, Token::Hand, origin)?;
+                let destination = origin + move_direction;
+                self.check_empty_destination(destination)?;
+                match self.carried_space(color, origin) {
+                    Some(carried) if !self.can_land_carried(carried, origin, destination + hand_direction) => {
+                        Err(IllegalMove::CarriedPieceBlocked)
+                    }
+                    _ => Ok(()),
+                }
+            }
+        }
+    }
+}
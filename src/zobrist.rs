@@ -0,0 +1,146 @@
+//! Zobrist keys for hashing a complete game state.
+//!
+//! A game's hash is the XOR of one key for every occupied board space, every token in a supply
+//! or graveyard, each player's Hand direction and the turn state. `Game` keeps it up to date as
+//! choices are applied, so `Game::hash` is free; `Game::compute_hash` rebuilds it from scratch.
+
+use crate::bitboard::CELLS;
+use crate::board::{Color, Space, Token, PIECES, TOKENS};
+use crate::coordinates::Direction;
+use crate::outcome::OutcomeReason;
+use crate::rules::{Game, TurnState};
+
+const DIRECTIONS: usize = 6;
+const REASONS: usize = 5;
+// First actions, second actions with every possible used piece and every outcome
+const TURN_STATES: usize = 2 * (1 + 1 + TOKENS) + 3 * REASONS;
+
+struct Keys {
+    spaces: [[u64; PIECES]; CELLS],
+    supplies: [[u64; TOKENS]; 2],
+    graveyards: [[u64; TOKENS]; 2],
+    hand_directions: [[u64; DIRECTIONS]; 2],
+    turn_states: [u64; TURN_STATES],
+}
+
+/// SplitMix64, returning the next state and its output.
+const fn next_key(state: u64) -> (u64, u64) {
+    let state = state.wrapping_add(0x9e3779b97f4a7c15);
+    let mut z = state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    (state, z ^ (z >> 31))
+}
+
+const fn generate_keys() -> Keys {
+    let mut keys = Keys {
+        spaces: [[0; PIECES]; CELLS],
+        supplies: [[0; TOKENS]; 2],
+        graveyards: [[0; TOKENS]; 2],
+        hand_directions: [[0; DIRECTIONS]; 2],
+        turn_states: [0; TURN_STATES],
+    };
+    let mut state = 0x726f6b6b75;
+    let mut key;
+
+    let mut cell = 0;
+    while cell < CELLS {
+        let mut piece = 0;
+        while piece < PIECES {
+            (state, key) = next_key(state);
+            keys.spaces[cell][piece] = key;
+            piece += 1;
+        }
+        cell += 1;
+    }
+
+    let mut color = 0;
+    while color < 2 {
+        let mut token = 0;
+        while token < TOKENS {
+            (state, key) = next_key(state);
+            keys.supplies[color][token] = key;
+            (state, key) = next_key(state);
+            keys.graveyards[color][token] = key;
+            token += 1;
+        }
+        let mut direction = 0;
+        while direction < DIRECTIONS {
+            (state, key) = next_key(state);
+            keys.hand_directions[color][direction] = key;
+            direction += 1;
+        }
+        color += 1;
+    }
+
+    let mut turn_state = 0;
+    while turn_state < TURN_STATES {
+        (state, key) = next_key(state);
+        keys.turn_states[turn_state] = key;
+        turn_state += 1;
+    }
+    keys
+}
+
+static KEYS: Keys = generate_keys();
+
+pub(crate) fn space_key(cell: usize, space: Space) -> u64 {
+    match space {
+        Space::Empty => 0,
+        Space::Occupied(piece) => KEYS.spaces[cell][piece.index()],
+    }
+}
+
+pub(crate) fn supply_key(color: Color, token: Token) -> u64 {
+    KEYS.supplies[color as usize][token as usize]
+}
+
+pub(crate) fn graveyard_key(color: Color, token: Token) -> u64 {
+    KEYS.graveyards[color as usize][token as usize]
+}
+
+pub(crate) fn hand_direction_key(color: Color, direction: Direction) -> u64 {
+    KEYS.hand_directions[color as usize][direction as usize]
+}
+
+pub(crate) fn turn_state_key(turn_state: &TurnState) -> u64 {
+    let used_piece_index = |used_piece: &Option<Token>| used_piece.map_or(0, |token| 1 + token as usize);
+    let index = match turn_state {
+        TurnState::WhiteFirstAction => 0,
+        TurnState::WhiteSecondAction { used_piece } => 1 + used_piece_index(used_piece),
+        TurnState::BlackFirstAction => 2 + TOKENS,
+        TurnState::BlackSecondAction { used_piece } => 3 + TOKENS + used_piece_index(used_piece),
+        TurnState::Over(outcome) => {
+            let winner = outcome.winner.map_or(0, |color| 1 + color as usize);
+            let reason = match outcome.reason {
+                OutcomeReason::BadRockDelivered => 0,
+                OutcomeReason::GoodRocksCollected => 1,
+                OutcomeReason::SplitGoodRocks => 2,
+                OutcomeReason::Repetition => 3,
+                OutcomeReason::TurnLimit => 4,
+            };
+            4 + 2 * TOKENS + winner * REASONS + reason
+        }
+    };
+    KEYS.turn_states[index]
+}
+
+impl Game {
+    /// Computes the hash of the game state without using the incrementally updated one.
+    pub fn compute_hash(&self) -> u64 {
+        let mut hash = turn_state_key(&self.turn_state);
+        for cell in 0..CELLS {
+            hash ^= space_key(cell, self.board.cell(cell));
+        }
+        for color in [Color::White, Color::Black] {
+            for &token in self.supplies[color as usize].iter() {
+                hash ^= supply_key(color, token);
+            }
+            for &token in self.graveyards[color as usize].iter() {
+                hash ^= graveyard_key(color, token);
+            }
+            hash ^= hand_direction_key(color, self.hand_directions[color as usize]);
+        }
+        hash
+    }
+}
@@ -0,0 +1,129 @@
+use strum::IntoEnumIterator;
+
+use rokku::mcts::Game as MctsGame;
+
+use rokku::{Choice, Color, Coordinates, Direction, Game, IllegalMove, Piece, Space, Token};
+
+/// A game on `board` with White to play its first action under `rules`.
+fn game_on(board: &str, supplies: &str, rules: &str) -> Game {
+    let mut game = Game::from_notation(&format!("{} {} - - L,R w1 0", board, supplies)).unwrap();
+    game.rules = rules.parse().unwrap();
+    game
+}
+
+fn choice(s: &str) -> Choice {
+    s.parse().unwrap()
+}
+
+/// Whether validation accepts exactly the generated choices among `candidates`.
+fn assert_validation_agrees(game: &Game, candidates: &[Choice]) {
+    let choices = game.get_all_choices();
+    for candidate in candidates {
+        assert_eq!(game.validate_choice(candidate).is_ok(), choices.contains(candidate), "{}", candidate);
+    }
+}
+
+// The White Wave at f7 below the bad rock at f6 and the Black Scout at f5
+const WAVE_BOARD: &str = "6/7/8/4d4/4s5/1Y3X3Z1/5W4/4D4/8/7/6";
+const WAVE_SUPPLIES: &str = "SMKHB MKWHB";
+
+#[test]
+fn the_buffed_wave_reflects_the_whole_line() {
+    let mut game = game_on(WAVE_BOARD, WAVE_SUPPLIES, "base,buffed-wave");
+    let wave = choice("W:f7>UL");
+    assert!(game.get_all_choices().contains(&wave));
+    assert_eq!(game.validate_choice(&wave), Ok(()));
+    game.apply_choice(&wave);
+
+    assert_eq!(game.board.get_space(Coordinates(0, 0, 0)), Space::Empty);
+    assert_eq!(game.board.get_space(Coordinates(0, -1, 1)), Space::Empty);
+    assert_eq!(game.board.get_space(Coordinates(0, 2, -2)), Space::Occupied(Piece::BadRock));
+    assert_eq!(game.board.get_space(Coordinates(0, 3, -3)), Space::Occupied(Piece::Token(Color::Black, Token::Scout)));
+    assert_eq!(game.board.get_space(Coordinates(0, 1, -1)), Space::Occupied(Piece::Token(Color::White, Token::Wave)));
+}
+
+#[test]
+fn the_buffed_wave_cannot_land_on_an_occupied_space() {
+    // The White Daimyo moved to f8, where the bad rock would land
+    let game = game_on("6/7/8/4d4/4s5/1Y3X3Z1/5W4/5D3/8/7/6", WAVE_SUPPLIES, "base,buffed-wave");
+    assert_eq!(game.validate_choice(&choice("W:f7>UL")), Err(IllegalMove::WaveLineBlocked));
+    assert_eq!(game.validate_choice(&choice("W:f7>DR")), Err(IllegalMove::WaveLineBlocked));
+    assert!(!game.get_all_choices().contains(&choice("W:f7>UL")));
+}
+
+#[test]
+fn wave_validation_agrees_with_generation() {
+    for board in [WAVE_BOARD, "6/7/8/4d4/4s5/1Y3X3Z1/5W4/5D3/8/7/6"] {
+        let mut waves: Vec<Choice> = Direction::iter().map(|direction| choice(&format!("W:f7>{}", direction))).collect();
+        waves.push(choice("W:f6-f8"));
+        let buffed_game = game_on(board, WAVE_SUPPLIES, "base,buffed-wave");
+        let game = game_on(board, WAVE_SUPPLIES, "base");
+        assert_validation_agrees(&buffed_game, &waves);
+        assert_validation_agrees(&game, &waves);
+
+        // Each Wave plays only under its own rule
+        assert_eq!(game.validate_choice(&waves[0]), Err(IllegalMove::WrongWave));
+        assert_eq!(buffed_game.validate_choice(&choice("W:f6-f8")), Err(IllegalMove::WrongWave));
+    }
+}
+
+#[test]
+fn a_held_piece_cannot_be_moved() {
+    // The Black Hand at e6 holds the bad rock at f6, next to the White Hammer at f7
+    let board = "6/7/8/4d4/10/1Y2hX3Z1/5M4/4D4/8/7/6";
+    let supplies = "SKWHB SMKWB";
+    let game = game_on(board, supplies, "base");
+    let hammer_the_rock: Vec<Choice> = game
+        .get_all_choices()
+        .into_iter()
+        .filter(|choice| choice.to_string().starts_with("M:f6>"))
+        .collect();
+    assert!(!hammer_the_rock.is_empty());
+
+    for rules in ["base,hand-grab=immobile", "base,hand-grab=carried"] {
+        let game = game_on(board, supplies, rules);
+        assert_validation_agrees(&game, &hammer_the_rock);
+        for choice in hammer_the_rock.iter() {
+            assert_eq!(game.validate_choice(choice), Err(IllegalMove::PieceHeld(Coordinates(0, 0, 0))));
+        }
+    }
+}
+
+// The White Hand at f7 facing left, where it holds the Black Scout at e7
+const HAND_BOARD: &str = "6/7/8/4d4/10/1Y3X3Z1/4sH4/4D4/8/7/6";
+const HAND_SUPPLIES: &str = "SMKWB MKWHB";
+
+#[test]
+fn the_hand_carries_the_held_piece() {
+    let mut game = game_on(HAND_BOARD, HAND_SUPPLIES, "base,hand-grab=carried");
+    let carry = choice("H:f7>R/DR");
+    assert!(game.get_all_choices().contains(&carry));
+    game.apply_choice(&carry);
+
+    assert_eq!(game.board.get_space(Coordinates(-1, 1, 0)), Space::Empty);
+    assert_eq!(game.board.get_space(Coordinates(1, 1, -2)), Space::Occupied(Piece::Token(Color::White, Token::Hand)));
+    assert_eq!(game.board.get_space(Coordinates(1, 2, -3)), Space::Occupied(Piece::Token(Color::Black, Token::Scout)));
+
+    // Without the carry the Scout stays
+    let mut game = game_on(HAND_BOARD, HAND_SUPPLIES, "base,hand-grab=immobile");
+    game.apply_choice(&carry);
+    assert_eq!(game.board.get_space(Coordinates(-1, 1, 0)), Space::Occupied(Piece::Token(Color::Black, Token::Scout)));
+    assert_eq!(game.board.get_space(Coordinates(1, 2, -3)), Space::Empty);
+}
+
+#[test]
+fn the_hand_cannot_move_where_the_carried_piece_cannot_land() {
+    // Moving to f8 facing left would land the Scout on the White Daimyo at e8
+    let blocked = choice("H:f7>DR/L");
+    let game = game_on(HAND_BOARD, HAND_SUPPLIES, "base,hand-grab=carried");
+    assert_eq!(game.validate_choice(&blocked), Err(IllegalMove::CarriedPieceBlocked));
+    let hand_choices: Vec<Choice> = Direction::iter()
+        .flat_map(|move_direction| Direction::iter().map(move |hand_direction| (move_direction, hand_direction)))
+        .map(|(move_direction, hand_direction)| choice(&format!("H:f7>{}/{}", move_direction, hand_direction)))
+        .collect();
+    assert_validation_agrees(&game, &hand_choices);
+
+    let game = game_on(HAND_BOARD, HAND_SUPPLIES, "base,hand-grab=immobile");
+    assert_eq!(game.validate_choice(&blocked), Ok(()));
+    assert_validation_agrees(&game, &hand_choices);
+}
@@ -0,0 +1,56 @@
+use std::io::Cursor;
+
+use rokku::mcts::Game as MctsGame;
+
+use rokku::{Agent, Choice, Color, Decision, Game, GreedyAgent, HumanAgent, RandomAgent};
+
+#[test]
+fn greedy_and_random_agents_play_legal_choices() {
+    let mut game = Game::new();
+    let mut white = GreedyAgent::new(1);
+    let mut black = RandomAgent::new(2);
+    while !game.is_terminal() && game.choice_number < 200 {
+        let choice = match game.get_active_player_id() {
+            Color::White => white.choose(&game),
+            Color::Black => black.choose(&game),
+        };
+        assert_eq!(game.validate_choice(&choice), Ok(()), "{}", choice);
+        game.apply_choice(&choice);
+    }
+}
+
+#[test]
+fn human_agent_reads_choices_by_number_or_notation() {
+    let game = Game::new();
+    let choices = game.get_all_choices();
+
+    let mut output = vec![];
+    let choice = HumanAgent::new(Cursor::new("list\nnonsense\n0\n2\n"), &mut output).choose(&game);
+    assert_eq!(choice, choices[1]);
+    let output = String::from_utf8(output).unwrap();
+    assert!(output.contains(&format!("   2. {}", choices[1])));
+    assert!(output.contains("There is no choice number 0"));
+
+    let mut output = vec![];
+    let choice = HumanAgent::new(Cursor::new("+M\n"), &mut output).choose(&game);
+    assert_eq!(choice, "+M".parse::<Choice>().unwrap());
+    assert_eq!(HumanAgent::new(Cursor::new(""), &mut output).choose(&game), Choice::Pass);
+}
+
+#[test]
+fn human_agent_decides_to_undo_resign_or_quit() {
+    let game = Game::new();
+    let mut output = vec![];
+    {
+        let mut human = HumanAgent::new(Cursor::new("board\nD:f6-f7\nundo\nresign\n+M\n"), &mut output);
+        assert_eq!(human.decide(&game), Decision::Undo);
+        assert_eq!(human.decide(&game), Decision::Resign);
+        assert_eq!(human.decide(&game), Decision::Play("+M".parse().unwrap()));
+        assert_eq!(human.decide(&game), Decision::Quit);
+    }
+    let output = String::from_utf8(output).unwrap();
+    assert!(output.starts_with("Enter a choice in notation"));
+    assert!(output.contains(&game.board.to_string()));
+    assert!(output.contains("Illegal choice: "));
+    assert!(output.contains("White to play, first action> "));
+}
@@ -0,0 +1,73 @@
+use rokku::mcts::Game as MctsGame;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use strum::IntoEnumIterator;
+
+use rokku::bitboard::{self, Bitboard, CELLS};
+use rokku::{Board, Color, Coordinates, Direction, Game, Piece, Space, Token};
+
+fn scan(board: &Board, piece: Piece) -> Option<Coordinates> {
+    (0..CELLS).map(bitboard::coordinates).find(|&coordinates| board.get_space(coordinates) == Space::Occupied(piece))
+}
+
+fn check_locations(board: &Board) {
+    let tokens = [Color::White, Color::Black].into_iter().flat_map(|color| Token::iter().map(move |token| Piece::Token(color, token)));
+    for piece in [Piece::GoodRock, Piece::GoodRock2, Piece::BadRock].into_iter().chain(tokens) {
+        assert_eq!(board.find(piece), scan(board, piece), "{:?}", piece);
+    }
+}
+
+#[test]
+fn cells_number_every_space_in_order() {
+    let spaces: Vec<Coordinates> = (-5..=5)
+        .flat_map(|q| (-5..=5).map(move |r| Coordinates(q, r, -q - r)))
+        .filter(|coordinates| !coordinates.is_off_board())
+        .collect();
+    assert_eq!(spaces.len(), CELLS);
+    for (cell, &coordinates) in spaces.iter().enumerate() {
+        assert_eq!(bitboard::coordinates(cell), coordinates);
+        assert_eq!(bitboard::cell(coordinates), Some(cell));
+    }
+    assert_eq!(bitboard::cell(Coordinates(5, 5, -10)), None);
+}
+
+#[test]
+fn neighbors_and_rays_follow_the_directions() {
+    for cell in 0..CELLS {
+        let coordinates = bitboard::coordinates(cell);
+        for direction in Direction::iter() {
+            assert_eq!(bitboard::neighbor(cell, direction), bitboard::cell(coordinates + direction));
+
+            let mut expected = Bitboard::EMPTY;
+            let mut next = coordinates + direction;
+            while let Some(next_cell) = bitboard::cell(next) {
+                expected.insert(next_cell);
+                next = next + direction;
+            }
+            let ray = bitboard::ray(cell, direction);
+            assert_eq!(ray, expected);
+            assert_eq!(ray.nearest(direction), bitboard::neighbor(cell, direction));
+        }
+    }
+}
+
+#[test]
+fn piece_locations_match_the_board() {
+    for rules in ["all", "all,buffed-wave,hand-grab=carried"] {
+        for seed in 0..5 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let mut game = Game::with_rules(rules.parse().unwrap());
+            while !game.is_terminal() && game.choice_number < 120 {
+                for choice in game.get_all_choices() {
+                    let undo = game.make(&choice);
+                    check_locations(&game.board);
+                    game.unmake(undo);
+                }
+                check_locations(&game.board);
+                let choice = *game.get_all_choices().choose(&mut rng).unwrap();
+                game.apply_choice(&choice);
+            }
+        }
+    }
+}
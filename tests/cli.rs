@@ -0,0 +1,70 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+use rokku::{Choice, GameRecord, GameResult};
+
+fn rokku(args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_rokku")).args(args).output().unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn selfplay_games_end_at_the_default_turn_limit() {
+    let mut at_limit = 0;
+    for seed in ["1", "2", "3"] {
+        let output = rokku(&["selfplay", "--agent", "random", "--seed", seed, "--format", "record"]);
+        let record: GameRecord = output.parse().unwrap();
+        assert_eq!(record.rules.max_turns, Some(200));
+        assert_ne!(record.result, GameResult::Unfinished);
+        assert!(record.choices.len() <= 400);
+        record.replay().unwrap();
+        if record.tags.contains(&(String::from("Termination"), String::from("turn limit"))) {
+            at_limit += 1;
+        }
+    }
+    // Random games are long enough that some only end at the limit
+    assert!(at_limit > 0);
+
+    let output = rokku(&["selfplay", "--agent", "random", "--rules", "all,max-turns=3", "--format", "record"]);
+    assert_eq!(output.parse::<GameRecord>().unwrap().rules.max_turns, Some(3));
+}
+
+/// Runs rokku with `input` on stdin, failing instead of hanging if it does not finish.
+fn rokku_with_input(args: &[&str], input: &str) {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_rokku"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+    let deadline = Instant::now() + Duration::from_secs(20);
+    while child.try_wait().unwrap().is_none() {
+        if Instant::now() >= deadline {
+            child.kill().unwrap();
+            panic!("rokku {} did not finish", args.join(" "));
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    }
+}
+
+#[test]
+fn two_people_share_the_terminal() {
+    let path = format!("{}/two_people.rok", env!("CARGO_TARGET_TMPDIR"));
+    // White deploys the Hammer and passes, then Black resigns
+    rokku_with_input(&["play", "white", "--opponent", "human", "--record", &path], "+M\npass\nresign\n");
+    let record: GameRecord = std::fs::read_to_string(&path).unwrap().parse().unwrap();
+    assert_eq!(record.choices, ["+M".parse().unwrap(), "pass".parse().unwrap()]);
+    assert_eq!(record.result, GameResult::WhiteWins);
+    assert_eq!(record.tags.iter().find(|(name, _)| name == "Black").map(|(_, name)| name.as_str()), Some("Human"));
+
+    let path = format!("{}/two_people_selfplay.rok", env!("CARGO_TARGET_TMPDIR"));
+    rokku_with_input(&["selfplay", "--agent", "human", "--opponent", "human", "--rules", "all,max-turns=2", "--record", &path], "+M\npass\n+M\n");
+    let record: GameRecord = std::fs::read_to_string(&path).unwrap().parse().unwrap();
+    // Black passes at the end of the input, at the turn limit
+    let expected: Vec<Choice> = ["+M", "pass", "+M", "pass"].iter().map(|choice| choice.parse().unwrap()).collect();
+    assert_eq!(record.choices, expected);
+    assert_eq!(record.result, GameResult::Draw);
+}
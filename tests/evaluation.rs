@@ -0,0 +1,51 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+
+use rokku::mcts::Game as MctsGame;
+
+use rokku::{Color, Game};
+
+#[test]
+fn evaluations_of_both_players_sum_to_one() {
+    let game = Game::new();
+    assert_eq!(game.evaluate(Color::White), 0.5);
+
+    let mut rng = StdRng::seed_from_u64(3);
+    for _ in 0..20 {
+        let mut game = Game::new();
+        while !game.is_terminal() {
+            let evaluation = game.evaluate(Color::White);
+            assert!(evaluation > 0.0 && evaluation < 1.0);
+            assert!((evaluation + game.evaluate(Color::Black) - 1.0).abs() < 1e-9);
+            assert_eq!(game.reward_for(Color::White), evaluation);
+            let choice = *game.get_all_choices().choose(&mut rng).unwrap();
+            game.apply_choice(&choice);
+        }
+        let winner = game.evaluate(Color::White);
+        assert!(winner == 0.0 || winner == 0.5 || winner == 1.0);
+        assert_eq!(game.reward_for(Color::White), winner);
+    }
+}
+
+#[test]
+fn a_winning_position_evaluates_above_a_losing_one() {
+    // The bad rock one step from Black's village, or from White's, with White to move
+    let winning = Game::from_notation("6/7/4X3/4d4/10/1Y7Z1/10/4D4/8/7/6 SMKWHB SMKWHB - - L,R w1 0").unwrap();
+    let losing = Game::from_notation("6/7/8/4d4/10/1Y7Z1/10/4D4/4X3/7/6 SMKWHB SMKWHB - - L,R w1 0").unwrap();
+    assert!(winning.evaluate(Color::White) > 0.5);
+    assert!(losing.evaluate(Color::White) < 0.5);
+    assert!(winning.evaluate(Color::Black) < losing.evaluate(Color::Black));
+
+    // White's Daimyo dead as well makes it worse
+    let without_daimyo = Game::from_notation("6/7/8/4d4/10/1Y7Z1/10/9/4X3/7/6 SMKWHB SMKWHB D - L,R w1 0").unwrap();
+    assert!(without_daimyo.evaluate(Color::White) < losing.evaluate(Color::White));
+}
+
+#[test]
+fn material_counts() {
+    // White's Bomb lost to the graveyard
+    let behind = Game::from_notation("6/7/8/4d4/10/1Y3X3Z1/10/4D4/8/7/6 SMKWH SMKWHB B - L,R w1 0").unwrap();
+    assert!(behind.evaluate(Color::White) < 0.5);
+    assert!(behind.evaluate(Color::Black) > 0.5);
+}
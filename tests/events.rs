@@ -0,0 +1,59 @@
+use rokku::mcts::Game as MctsGame;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+
+use rokku::{Color, Coordinates, Direction, Event, Game, Piece, Token};
+
+#[test]
+fn deploying_a_hand_reports_its_rotation() {
+    let mut game = Game::new();
+    let events = game.apply_choice_with_events(&"+H/DL".parse().unwrap());
+    assert_eq!(
+        events,
+        vec![
+            Event::Deployed { color: Color::White, token: Token::Hand, to: Coordinates(-2, 4, -2) },
+            Event::HandRotated { color: Color::White, from: Direction::Left, to: Direction::DownLeft },
+        ]
+    );
+    assert_eq!(game.apply_choice_with_events(&"pass".parse().unwrap()), vec![]);
+}
+
+#[test]
+fn moves_report_where_the_piece_went() {
+    let mut game = Game::new();
+    let events = game.apply_choice_with_events(&"D>UL".parse().unwrap());
+    assert_eq!(
+        events,
+        vec![Event::Moved { piece: Piece::Token(Color::White, Token::Daimyo), from: Coordinates(-1, 2, -1), to: Coordinates(-1, 1, 0) }]
+    );
+}
+
+#[test]
+fn events_agree_with_the_game() {
+    for rules in ["base", "all", "all,buffed-wave,hand-grab=carried"] {
+        for seed in 0..50 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let mut game = Game::with_rules(rules.parse().unwrap());
+            while !game.is_terminal() && game.choice_number < 300 {
+                let choice = *game.get_all_choices().choose(&mut rng).unwrap();
+                let mut expected = game.clone();
+                expected.apply_choice(&choice);
+                let events = game.apply_choice_with_events(&choice);
+                assert_eq!(game.to_notation(), expected.to_notation());
+                assert_eq!(game.hash(), expected.hash());
+
+                for event in &events {
+                    if let Event::Pushed { from, to, distance, .. } = *event {
+                        assert!(distance > 0 && from.distance(to) == distance, "{:?}", event);
+                    }
+                }
+                let ended = events.iter().position(|event| matches!(event, Event::GameEnded(_)));
+                assert_eq!(ended.is_some(), game.is_terminal(), "{} {:?}", choice, events);
+                if let Some(index) = ended {
+                    assert_eq!(index, events.len() - 1);
+                }
+            }
+        }
+    }
+}
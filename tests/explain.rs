@@ -0,0 +1,57 @@
+use rokku::mcts::Game as MctsGame;
+
+use rokku::{Choice, Game, GameRecord};
+
+fn explain_all(game: &mut Game, choices: &[&str]) -> Vec<String> {
+    choices
+        .iter()
+        .map(|choice| {
+            let choice: Choice = choice.parse().unwrap();
+            let explanation = game.explain_choice(&choice);
+            game.apply_choice(&choice);
+            explanation
+        })
+        .collect()
+}
+
+#[test]
+fn explains_deploys_and_moves() {
+    let mut game = Game::new();
+    assert_eq!(
+        explain_all(&mut game, &["+H/DL", "D>UL", "pass"]),
+        [
+            "White deploys the Hand at d10 facing down-left",
+            "White Daimyo moves up-left to e7",
+            "Black passes",
+        ]
+    );
+}
+
+#[test]
+fn explains_what_happens_to_the_target() {
+    let mut game = Game::new();
+    explain_all(&mut game, &["+M", "M>UR", "pass", "pass"]);
+    assert_eq!(
+        explain_all(&mut game, &["M:e8>UL2"]),
+        ["White Hammer pushes White Daimyo two spaces up-left"]
+    );
+}
+
+#[test]
+fn comments_survive_a_record_round_trip() {
+    let mut game = Game::new();
+    let mut record = GameRecord::new(&game);
+    for choice in ["+S", "pass", "D>DR"] {
+        let choice: Choice = choice.parse().unwrap();
+        let explanation = game.explain_choice(&choice);
+        game.apply_choice(&choice);
+        record.push(choice, &game);
+        record.comment(&explanation);
+    }
+    let text = record.to_string();
+    assert!(text.contains("1. +S {White deploys the Scout at d10} pass {White passes} D>DR {Black Daimyo moves down-right to g5}"));
+    assert_eq!(text.parse::<GameRecord>().unwrap(), record);
+
+    record.pop();
+    assert_eq!(record.comments.len(), 2);
+}
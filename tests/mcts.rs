@@ -0,0 +1,61 @@
+use rokku::mcts::Game as MctsGame;
+
+use rokku::{Choice, Game, Mcts, MctsSettings};
+
+#[test]
+fn searches_with_the_same_seed_agree() {
+    let game = Game::new();
+    let settings = MctsSettings { seed: 7, ..MctsSettings::default() };
+    let first = Mcts::new(settings).search(&game, 500, None);
+    let second = Mcts::new(settings).search(&game, 500, None);
+    assert_eq!(first, second);
+    assert_eq!(first.iterations, 500);
+    assert_eq!(first.children.len(), game.get_all_choices().len());
+    assert_eq!(first.children.iter().map(|child| child.visits).sum::<u32>(), 500);
+    assert_eq!(first.choice, first.children[0].choice);
+}
+
+#[test]
+fn keeps_the_subtree_of_the_position_reached() {
+    let game = Game::new();
+    for reuse_tree in [true, false] {
+        let mut mcts = Mcts::new(MctsSettings { reuse_tree, ..MctsSettings::default() });
+        let result = mcts.search(&game, 2000, None);
+        let mut next = game.clone();
+        next.apply_choice(&result.choice);
+        let reply = next.get_all_choices()[0];
+        next.apply_choice(&reply);
+
+        let result = mcts.search(&next, 1, None);
+        let visits: u32 = result.children.iter().map(|child| child.visits).sum();
+        assert_eq!(visits > 1, reuse_tree);
+    }
+}
+
+#[test]
+fn positions_closer_to_a_draw_are_not_reused() {
+    // A round of four passes comes back to the position, with fewer turns left and one more repetition
+    let game = Game::with_rules("all,max-turns=3,draw-by-repetition".parse().unwrap());
+    let mut next = game.clone();
+    for _ in 0..4 {
+        next.apply_choice(&Choice::Pass);
+    }
+    assert_eq!(next.hash(), game.hash());
+    assert_ne!(next.position_key(), game.position_key());
+
+    let mut mcts = Mcts::new(MctsSettings::default());
+    mcts.search(&game, 2000, None);
+    let result = mcts.search(&next, 1, None);
+    let visits: u32 = result.children.iter().map(|child| child.visits).sum();
+    assert!(visits < 1000, "{}", visits);
+}
+
+#[test]
+#[should_panic(expected = "Cannot search a game that is over")]
+fn refuses_to_search_a_finished_game() {
+    let mut game = Game::with_rules("all,max-turns=1".parse().unwrap());
+    game.apply_choice(&Choice::Pass);
+    game.apply_choice(&Choice::Pass);
+    assert!(game.is_terminal());
+    Mcts::new(MctsSettings::default()).search(&game, 10, None);
+}
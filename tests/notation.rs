@@ -0,0 +1,50 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+
+use rokku::mcts::Game as MctsGame;
+
+use rokku::{Choice, Coordinates, Game, NotationError, RuleSet};
+
+#[test]
+fn every_generated_choice_round_trips() {
+    for (seed, rules) in ["all", "all,buffed-wave,daimyo-turns-hand", "base"].into_iter().enumerate() {
+        let rules: RuleSet = rules.parse().unwrap();
+        let mut rng = StdRng::seed_from_u64(seed as u64);
+        for _ in 0..5 {
+            let mut game = Game::with_rules(rules);
+            while !game.is_terminal() && game.choice_number < 300 {
+                let choices = game.get_all_choices();
+                for choice in choices.iter() {
+                    assert_eq!(choice.to_string().parse::<Choice>(), Ok(*choice), "{}", choice);
+                }
+                game.apply_choice(choices.choose(&mut rng).unwrap());
+            }
+        }
+    }
+}
+
+#[test]
+fn coordinates_round_trip() {
+    for q in -5..=5 {
+        for r in -5..=5 {
+            let coordinates = Coordinates(q, r, -q - r);
+            if !coordinates.is_off_board() {
+                assert_eq!(coordinates.to_string().parse(), Ok(coordinates));
+            }
+        }
+    }
+}
+
+#[test]
+fn malformed_choices_are_rejected() {
+    let error = |s: &str| s.parse::<Choice>().unwrap_err();
+    assert_eq!(error("X>L"), NotationError::UnknownToken(String::from("X")));
+    assert_eq!(error("M>UP"), NotationError::UnknownDirection(String::from("UP")));
+    assert_eq!(error("M:f6>UR"), NotationError::InvalidDistance(String::new()));
+    assert_eq!(error("M:f6"), NotationError::Malformed(String::from("M:f6")));
+    assert_eq!(error("hello"), NotationError::Malformed(String::from("hello")));
+    for coordinates in ["a-128", "k-125", "a1", "l6", "f12", "f", "f6x", "f+6", "f06", "f 6", "f٦"] {
+        assert_eq!(error(&format!("B:{}", coordinates)), NotationError::InvalidCoordinates(coordinates.to_string()));
+    }
+}
@@ -0,0 +1,151 @@
+use rokku::{perft, perft_counts, Game, PerftCounts, RuleSet};
+
+const fn counts(nodes: u64, passes: u64, deploys: u64, moves: u64, abilities: u64, revives: u64) -> PerftCounts {
+    PerftCounts { nodes, passes, deploys, moves, abilities, revives }
+}
+
+/// A reference position, the rules it is searched under and the expected counts from depth 1.
+struct Reference {
+    position: &'static str,
+    rules: &'static str,
+    expected: &'static [PerftCounts],
+}
+
+const INITIAL: &str = "6/7/8/4d4/10/1Y3X3Z1/10/4D4/8/7/6 SMKWHB SMKWHB - - L,R w1 0";
+
+/// The counts of the initial position were worked out by hand from `get_all_choices` of the
+/// original engine (`src/main.rs` at the baseline commit), which only differs from the rules
+/// here in its variants, none of which come into play this early:
+///
+/// - Depth 1: a pass, 11 deploys (5 tokens and the Hand in 6 directions) and 6 moves of the
+///   Daimyo at e8. It has no ability, with no other token to teleport.
+/// - Depth 2, the second action after each of those: 18 after the pass; 12 after each Daimyo
+///   move (a pass and the 11 deploys); after a deploy to d10, a pass, 6 Daimyo moves, 6
+///   teleports of the new token and its own 6 moves, with nothing in reach of its ability
+///   except the Bomb's 1 (19 or 20 choices), or for the Hand 36 ability moves instead (49).
+///   18 + 6 * 12 + 4 * 19 + 20 + 6 * 49 = 480.
+/// - Depths 3 and 4: Black's turn mirrors White's without the pieces meeting, so it
+///   multiplies the counts of depths 1 and 2 by 480.
+///
+/// The counts of the other positions were produced by this engine, so they only guard against
+/// regressions.
+const REFERENCES: &[Reference] = &[
+    Reference {
+        position: INITIAL,
+        rules: "all",
+        expected: &[
+            counts(18, 1, 11, 6, 0, 0),
+            counts(480, 18, 77, 102, 283, 0),
+            counts(8640, 480, 5280, 2880, 0, 0),
+            counts(230400, 8640, 36960, 48960, 135840, 0),
+        ],
+    },
+    Reference {
+        position: INITIAL,
+        rules: "base",
+        expected: &[
+            counts(18, 1, 11, 6, 0, 0),
+            counts(480, 18, 77, 102, 283, 0),
+            counts(8640, 480, 5280, 2880, 0, 0),
+        ],
+    },
+    Reference {
+        position: "2h3/3w3/4d3/9/10/1Y3X3Z1/10/9/4B3/3D3/6 SMKWH SMKB - - L,UL w1 20",
+        rules: "all",
+        expected: &[
+            counts(17, 1, 0, 10, 6, 0),
+            counts(216, 17, 60, 91, 48, 0),
+            counts(8208, 216, 0, 1944, 6048, 0),
+        ],
+    },
+    Reference {
+        position: "6/7/4h3/2d6/3w6/1Y3X3Z1/3K6/3M5/1m6/3H3/D5 SWB SKB - - DR,UL w2 41",
+        rules: "all",
+        expected: &[
+            counts(68, 1, 0, 13, 54, 0),
+            counts(4880, 68, 204, 1067, 3541, 0),
+            counts(230051, 4880, 12858, 66013, 146300, 0),
+        ],
+    },
+    Reference {
+        position: "6/7/2k1d3/2h1m4/10/1Y7Z1/5X4/9/3DS3/H2KM2/6 B SWB W - DL,DR b1 70",
+        rules: "all",
+        expected: &[
+            counts(71, 1, 3, 15, 52, 0),
+            counts(3624, 71, 192, 892, 2468, 1),
+            counts(242813, 3624, 0, 50736, 184829, 3624),
+        ],
+    },
+    Reference {
+        position: "6/7/2k1d3/2h1m4/10/1Y7Z1/5X4/9/3DS3/H2KM2/6 B SWB W - DL,DR b1 70",
+        rules: "base",
+        expected: &[
+            counts(71, 1, 3, 15, 52, 0),
+            counts(3624, 71, 193, 892, 2468, 0),
+            counts(239189, 3624, 0, 50736, 184829, 0),
+        ],
+    },
+    Reference {
+        position: "6/7/5hb1/5kd2/7X2/1Y4W2Z1/5D4/9/8/3K3/4H1 MBS MW - S DR,UL w1 120",
+        rules: "all",
+        expected: &[
+            counts(57, 1, 0, 16, 40, 0),
+            counts(2227, 57, 33, 721, 1411, 5),
+            counts(86670, 2227, 4454, 21830, 55931, 2228),
+        ],
+    },
+    Reference {
+        position: "6/7/5hb1/5kd2/7X2/1Y4W2Z1/5D4/9/8/3K3/4H1 MBS MW - S DR,UL w1 120",
+        rules: "base",
+        expected: &[
+            counts(57, 1, 0, 16, 40, 0),
+            counts(2267, 57, 33, 726, 1451, 0),
+            counts(86356, 2267, 4534, 22223, 57332, 0),
+        ],
+    },
+];
+
+fn load(reference: &Reference) -> Game {
+    let mut game = Game::from_notation(reference.position).unwrap();
+    game.rules = reference.rules.parse().unwrap();
+    game
+}
+
+#[test]
+fn perft_matches_reference_counts() {
+    for reference in REFERENCES {
+        let mut game = load(reference);
+        for (depth, expected) in (1..).zip(reference.expected) {
+            assert_eq!(
+                perft_counts(&mut game, depth),
+                *expected,
+                "perft({}) of {} with {} rules",
+                depth,
+                reference.position,
+                reference.rules
+            );
+        }
+    }
+}
+
+#[test]
+fn perft_agrees_with_perft_counts() {
+    for reference in REFERENCES {
+        let mut game = load(reference);
+        assert_eq!(perft(&mut game, 2), reference.expected[1].nodes);
+    }
+}
+
+#[test]
+fn perft_leaves_the_position_unchanged() {
+    for reference in REFERENCES {
+        let mut game = load(reference);
+        perft_counts(&mut game, 2);
+        assert_eq!(game.to_notation(), reference.position);
+    }
+}
+
+#[test]
+fn initial_position_matches_new_game() {
+    assert_eq!(Game::with_rules(RuleSet::base()).to_notation(), INITIAL);
+}
@@ -0,0 +1,56 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+
+use rokku::mcts::Game as MctsGame;
+
+use rokku::position::Location;
+use rokku::{Color, Game, Piece, PositionError, Token};
+
+const INITIAL: &str = "6/7/8/4d4/10/1Y3X3Z1/10/4D4/8/7/6 SMKWHB SMKWHB - - L,R w1 0";
+
+#[test]
+fn positions_round_trip() {
+    assert_eq!(Game::new().to_notation(), INITIAL);
+    let mut rng = StdRng::seed_from_u64(4);
+    let mut game = Game::new();
+    while !game.is_terminal() {
+        let notation = game.to_notation();
+        let parsed = Game::from_notation(&notation).unwrap();
+        assert_eq!(parsed.to_notation(), notation);
+        assert_eq!(parsed.get_all_choices(), game.get_all_choices());
+        let choice = *game.get_all_choices().choose(&mut rng).unwrap();
+        game.apply_choice(&choice);
+    }
+}
+
+#[test]
+fn invalid_positions_are_rejected() {
+    let error = |position: &str| Game::from_notation(position).err().unwrap();
+    let with_board = |board: &str| INITIAL.replacen("6/7/8/4d4/10/1Y3X3Z1/10/4D4/8/7/6", board, 1);
+
+    assert_eq!(error("6/7/8 SMKWHB"), PositionError::WrongFieldCount(2));
+    assert_eq!(error(&with_board("6/7/8/4d4/10/1Y3X3Z1/10/4D4/8/7")), PositionError::WrongRankCount(10));
+    assert_eq!(error(&with_board("5/7/8/4d4/10/1Y3X3Z1/10/4D4/8/7/6")), PositionError::WrongRankLength { rank: 1, length: 5, expected: 6 });
+    assert_eq!(
+        error(&with_board("99999999999999999999999/7/8/4d4/10/1Y3X3Z1/10/4D4/8/7/6")),
+        PositionError::WrongRankLength { rank: 1, length: 9, expected: 6 }
+    );
+    assert_eq!(
+        error(&with_board("6/7/8/4d4/10/1Y3X3Z1/10/4D4/8/7/5Q")),
+        PositionError::UnknownPiece('Q')
+    );
+    assert_eq!(error(&with_board("6/7/8/4d4/10/1Y3X3Z1/10/4D4/8/7/5X")), PositionError::DuplicatePiece(Piece::BadRock));
+    assert_eq!(error(&with_board("6/7/8/4d4/10/1Y7Z1/10/4D4/8/7/6")), PositionError::MissingRock(Piece::BadRock));
+    assert_eq!(
+        error(&INITIAL.replacen("SMKWHB - -", "SMKWHB D -", 1)),
+        PositionError::TokenInTwoPlaces { color: Color::White, token: Token::Daimyo, first: Location::Board, second: Location::Graveyard }
+    );
+    assert_eq!(
+        error(&INITIAL.replacen("SMKWHB SMKWHB", "SMKWH SMKWHB", 1)),
+        PositionError::MissingToken { color: Color::White, token: Token::Bomb }
+    );
+    assert_eq!(error(&INITIAL.replacen("L,R", "L", 1)), PositionError::InvalidHandDirections(String::from("L")));
+    assert_eq!(error(&INITIAL.replacen("w1", "w3", 1)), PositionError::InvalidTurnState(String::from("w3")));
+    assert_eq!(error(&INITIAL.replacen("w1 0", "w1 x", 1)), PositionError::InvalidChoiceNumber(String::from("x")));
+}
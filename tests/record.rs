@@ -0,0 +1,61 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+
+use rokku::mcts::Game as MctsGame;
+
+use rokku::record::{RecordError, ReplayError};
+use rokku::{Choice, Game, GameRecord, GameResult, NotationError, RuleSet, Token};
+
+fn random_game(seed: u64) -> (Game, GameRecord) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut game = Game::with_rules("all,max-turns=100".parse::<RuleSet>().unwrap());
+    let mut record = GameRecord::new(&game);
+    record.set_tag("White", "Random");
+    record.set_tag("Black", "Random \"quoted\"");
+    while !game.is_terminal() {
+        let choice = *game.get_all_choices().choose(&mut rng).unwrap();
+        game.apply_choice(&choice);
+        record.push(choice, &game);
+    }
+    (game, record)
+}
+
+#[test]
+fn records_round_trip_and_replay() {
+    for seed in 0..3 {
+        let (game, record) = random_game(seed);
+        let parsed: GameRecord = record.to_string().parse().unwrap();
+        assert_eq!(parsed, record);
+        assert_ne!(parsed.result, GameResult::Unfinished);
+        assert_eq!(parsed.replay().unwrap().to_notation(), game.to_notation());
+    }
+}
+
+#[test]
+fn replay_reports_the_number_of_the_bad_choice() {
+    let (_, record) = random_game(1);
+
+    let mut illegal = record.clone();
+    illegal.choices[6] = Choice::Revive(Token::Daimyo);
+    let error = illegal.replay().err().unwrap();
+    assert_eq!(error, ReplayError::IllegalChoice { index: 6, choice: Choice::Revive(Token::Daimyo) });
+    assert_eq!(error.to_string(), "Choice 7 (^D) is not legal");
+
+    let mut too_long = record.clone();
+    too_long.choices.push(Choice::Pass);
+    assert_eq!(too_long.replay().err().unwrap(), ReplayError::GameAlreadyOver { index: record.choices.len(), choice: Choice::Pass });
+
+    let mut wrong_result = record.clone();
+    wrong_result.result = match record.result {
+        GameResult::WhiteWins => GameResult::BlackWins,
+        _ => GameResult::WhiteWins,
+    };
+    assert!(matches!(wrong_result.replay(), Err(ReplayError::ResultMismatch { .. })));
+
+    let text = record.to_string().replacen(&format!(" {} ", record.choices[2]), " M>XX ", 1);
+    assert_eq!(
+        text.parse::<GameRecord>().unwrap_err(),
+        RecordError::InvalidChoice { index: 2, error: NotationError::UnknownDirection(String::from("XX")) }
+    );
+}
@@ -0,0 +1,41 @@
+use rokku::mcts::Game as MctsGame;
+
+use rokku::{Game, MctsSettings, RolloutWeights, SearchBudget, Searcher};
+
+#[test]
+fn weights_round_trip_through_text() {
+    let weights: RolloutWeights = "bad-rock=2,edge=0,candidates=10".parse().unwrap();
+    assert_eq!(weights, RolloutWeights { bad_rock: 2.0, edge: 0.0, candidates: 10, ..RolloutWeights::default() });
+    assert_eq!(weights.to_string().parse::<RolloutWeights>(), Ok(weights));
+
+    let uniform: RolloutWeights = "uniform".parse().unwrap();
+    assert!(uniform.is_uniform());
+    assert_eq!(uniform.to_string(), "uniform");
+    assert!(!"uniform,material=1".parse::<RolloutWeights>().unwrap().is_uniform());
+    assert!("pass=1".parse::<RolloutWeights>().is_err());
+}
+
+#[test]
+fn weights_must_be_finite() {
+    for value in ["inf", "-inf", "NaN", "1e400"] {
+        assert!(format!("win={}", value).parse::<RolloutWeights>().is_err(), "{}", value);
+    }
+    assert!("win=1e308".parse::<RolloutWeights>().is_ok());
+}
+
+#[test]
+fn playouts_survive_extreme_weights() {
+    let extremes = [
+        RolloutWeights { win: 1e308, bad_rock: 1e308, ..RolloutWeights::default() },
+        RolloutWeights { bad_rock: -1e308, good_rocks: 1e308, material: 1e308, ..RolloutWeights::default() },
+        RolloutWeights { bad_rock: 1e6, daimyo: -1e6, edge: 1e6, ..RolloutWeights::default() },
+        RolloutWeights { bad_rock: -1e6, good_rocks: -1e6, material: -1e6, daimyo: -1e6, edge: -1e6, win: -1e6, candidates: 91 },
+    ];
+    for (seed, rollout) in extremes.into_iter().enumerate() {
+        let settings = MctsSettings { seed: seed as u64, playout_depth: None, ..MctsSettings::default() };
+        let mut game = Game::with_rules("all,max-turns=60".parse().unwrap());
+        let result = Searcher::with_settings(SearchBudget::iterations(30), settings, rollout).search(&game);
+        assert_eq!(result.iterations, 30);
+        game.apply_choice(&result.choice);
+    }
+}
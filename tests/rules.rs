@@ -0,0 +1,87 @@
+use rokku::mcts::Game as MctsGame;
+
+use rokku::{Choice, Color, Game, GameOutcome, OutcomeReason, RuleSet, TurnState};
+
+fn outcome(game: &Game) -> Option<GameOutcome> {
+    match game.turn_state {
+        TurnState::Over(outcome) => Some(outcome),
+        _ => None,
+    }
+}
+
+#[test]
+fn rules_round_trip_and_draws_are_opt_in() {
+    assert!(!RuleSet::default().draw_by_repetition);
+    assert!(!RuleSet::default().draw_if_bad_rock_centered);
+    for rules in ["all", "base", "all,draw-by-repetition", "all,draw-if-bad-rock-centered,draw-by-repetition", "perma-death,draw-by-repetition,max-turns=40"] {
+        let parsed: RuleSet = rules.parse().unwrap();
+        assert_eq!(parsed.to_string(), rules);
+    }
+    assert!("all,max-turns=0".parse::<RuleSet>().is_err());
+    assert!("all,max-turns=x".parse::<RuleSet>().is_err());
+}
+
+#[test]
+fn games_are_drawn_at_the_turn_limit() {
+    let mut game = Game::with_rules("all,max-turns=3".parse().unwrap());
+    for _ in 0..5 {
+        game.apply_choice(&Choice::Pass);
+        assert_eq!(outcome(&game), None);
+    }
+    game.apply_choice(&Choice::Pass);
+    assert_eq!(outcome(&game), Some(GameOutcome { winner: None, reason: OutcomeReason::TurnLimit }));
+}
+
+#[test]
+fn games_are_drawn_at_the_third_repetition() {
+    // The initial position comes back after every full round of four passes
+    let mut game = Game::with_rules("all,draw-by-repetition".parse().unwrap());
+    for _ in 0..7 {
+        game.apply_choice(&Choice::Pass);
+        assert_eq!(outcome(&game), None);
+    }
+    game.apply_choice(&Choice::Pass);
+    assert_eq!(game.choice_number, 8);
+    assert_eq!(outcome(&game), Some(GameOutcome { winner: None, reason: OutcomeReason::Repetition }));
+
+    let mut game = Game::new();
+    for _ in 0..40 {
+        game.apply_choice(&Choice::Pass);
+    }
+    assert_eq!(outcome(&game), None);
+}
+
+/// The outcome of the rocks placed on `board` under `rules`.
+fn rock_outcome(board: &str, rules: &str) -> Option<GameOutcome> {
+    let mut game = Game::from_notation(&format!("{} SMKWHB SMKWHB - - L,R w1 0", board)).unwrap();
+    game.rules = rules.parse().unwrap();
+    game.rock_outcome()
+}
+
+#[test]
+fn simultaneous_rock_outcomes_follow_the_precedence() {
+    // Every rock in White's village: White collected the good rocks, but the bad rock was delivered
+    let all_in_white_village = "6/7/8/4d4/10/11/10/4D4/8/XYZ4/6";
+    assert_eq!(
+        rock_outcome(all_in_white_village, "all"),
+        Some(GameOutcome { winner: Some(Color::White), reason: OutcomeReason::GoodRocksCollected })
+    );
+    assert_eq!(
+        rock_outcome(all_in_white_village, "all,precedence=bad-rock>good-rocks>split-good-rocks"),
+        Some(GameOutcome { winner: Some(Color::Black), reason: OutcomeReason::BadRockDelivered })
+    );
+
+    // The good rocks split and the bad rock in White's village
+    let split = "6/Z6/8/4d4/10/11/10/4D4/8/XY5/6";
+    assert_eq!(rock_outcome(split, "all"), Some(GameOutcome { winner: Some(Color::Black), reason: OutcomeReason::BadRockDelivered }));
+    assert_eq!(
+        rock_outcome(split, "all,precedence=split-good-rocks>good-rocks>bad-rock"),
+        Some(GameOutcome { winner: Some(Color::Black), reason: OutcomeReason::SplitGoodRocks })
+    );
+    assert_eq!(rock_outcome(split, "base"), Some(GameOutcome { winner: Some(Color::Black), reason: OutcomeReason::BadRockDelivered }));
+
+    // The good rocks split and the bad rock on the center line
+    let centered = "6/Z6/8/4d4/10/5X5/10/4D4/8/Y6/6";
+    assert_eq!(rock_outcome(centered, "all"), None);
+    assert_eq!(rock_outcome(centered, "all,draw-if-bad-rock-centered"), Some(GameOutcome { winner: None, reason: OutcomeReason::SplitGoodRocks }));
+}
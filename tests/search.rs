@@ -0,0 +1,47 @@
+use std::time::{Duration, Instant};
+
+use rokku::mcts::Game as MctsGame;
+
+use rokku::{Game, MctsSettings, RolloutWeights, SearchBudget, Searcher};
+
+const INITIAL: &str = "6/7/8/4d4/10/1Y3X3Z1/10/4D4/8/7/6 SMKWHB SMKWHB - - L,R w1 0";
+
+fn searcher(budget: SearchBudget) -> Searcher {
+    Searcher::with_settings(budget, MctsSettings { seed: 3, ..MctsSettings::default() }, RolloutWeights::default())
+}
+
+#[test]
+fn the_iteration_cap_bounds_the_search() {
+    let game = Game::new();
+    let first = searcher(SearchBudget::iterations(300)).search(&game);
+    let second = searcher(SearchBudget::iterations(300)).search(&game);
+    assert_eq!(first.iterations, 300);
+    assert_eq!(first, second);
+
+    // The cap is reached long before the time limit
+    let budget = SearchBudget { max_iterations: Some(300), ..SearchBudget::per_action(Duration::from_secs(60)) };
+    assert_eq!(searcher(budget).search(&game), first);
+
+    assert_eq!(searcher(SearchBudget::iterations(0)).search(&game).iterations, 1);
+}
+
+#[test]
+fn the_turn_budget_is_shared_between_the_actions() {
+    // A loaded position can start a turn at an odd choice number
+    for position in [INITIAL, &INITIAL.replace("w1 0", "w1 1")] {
+        let per_turn = Duration::from_millis(400);
+        let mut searcher = searcher(SearchBudget::per_turn(per_turn));
+        let mut game = Game::from_notation(position).unwrap();
+
+        let start = Instant::now();
+        let choice = searcher.choose(&game);
+        let first = start.elapsed();
+        game.apply_choice(&choice);
+        searcher.choose(&game);
+        let turn = start.elapsed();
+
+        // The first action leaves half of the turn for the second
+        assert!(first >= per_turn / 2 && first < per_turn * 3 / 4, "{:?}", first);
+        assert!(turn >= per_turn && turn < per_turn * 5 / 4, "{:?} from {}", turn, position);
+    }
+}
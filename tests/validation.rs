@@ -0,0 +1,75 @@
+use std::collections::HashSet;
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+
+use rokku::mcts::Game as MctsGame;
+
+use rokku::{Choice, Coordinates, Game, IllegalMove, RuleSet, Token};
+
+/// Plays a random game, returning every state reached.
+fn random_states(rules: RuleSet, seed: u64) -> Vec<Game> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut game = Game::with_rules(rules);
+    let mut states = vec![];
+    while !game.is_terminal() && game.choice_number < 150 {
+        states.push(game.clone());
+        let choice = *game.get_all_choices().choose(&mut rng).unwrap();
+        game.apply_choice(&choice);
+    }
+    states.push(game);
+    states
+}
+
+#[test]
+fn validation_accepts_exactly_the_generated_choices() {
+    for (seed, rules) in ["all", "all,buffed-wave,step-off-edge", "all,hand-grab=carried", "base"].into_iter().enumerate() {
+        let states = random_states(rules.parse().unwrap(), seed as u64);
+        // Choices legal somewhere in the game, as candidates for every state
+        let candidates: HashSet<Choice> = states.iter().flat_map(|state| state.get_all_choices()).collect();
+        for state in states.iter() {
+            let choices = state.get_all_choices();
+            for candidate in candidates.iter() {
+                assert_eq!(
+                    state.validate_choice(candidate).is_ok(),
+                    !state.is_terminal() && choices.contains(candidate),
+                    "{} in {}",
+                    candidate,
+                    state.to_notation(),
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn illegal_choices_are_explained() {
+    let check = |game: &Game, choice: &str, error: IllegalMove| {
+        assert_eq!(game.validate_choice(&choice.parse().unwrap()), Err(error), "{}", choice);
+    };
+    let mut game = Game::new();
+    check(&game, "M>L", IllegalMove::TokenNotOnBoard(Token::Hammer));
+    check(&game, "+H", IllegalMove::HandDirectionRequired);
+    check(&game, "+M/L", IllegalMove::UnexpectedHandDirection);
+    check(&game, "+D", IllegalMove::NotInSupply(Token::Daimyo));
+    check(&game, "^M", IllegalMove::NotInGraveyard(Token::Hammer));
+    check(&game, "D:e8-e9", IllegalMove::DaimyoCannotTeleportItself);
+    check(&game, "D:f6-f7", IllegalMove::NotYourToken(Coordinates(0, 0, 0)));
+    game.apply_choice(&"D>L".parse().unwrap());
+    check(&game, "D>R", IllegalMove::TokenAlreadyUsed(Token::Daimyo));
+
+    let mut game = Game::with_rules(RuleSet::base());
+    check(&game, "^M", IllegalMove::ReviveNotAllowed);
+    game.apply_choice(&"+M".parse().unwrap());
+    check(&game, "+S", IllegalMove::GateOccupied);
+    check(&game, "M:e8>UL9", IllegalMove::OutOfRange(Coordinates(-1, 2, -1)));
+
+    let mut rng = StdRng::seed_from_u64(0);
+    let mut game = Game::new();
+    while !game.is_terminal() {
+        let choice = *game.get_all_choices().choose(&mut rng).unwrap();
+        game.apply_choice(&choice);
+    }
+    check(&game, "pass", IllegalMove::GameOver);
+}
@@ -0,0 +1,69 @@
+use rokku::mcts::Game as MctsGame;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+
+use rokku::{Choice, Game, RuleSet};
+
+fn random_games(rules: RuleSet, games: u64, check: impl Fn(&Game)) {
+    for seed in 0..games {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut game = Game::with_rules(rules);
+        check(&game);
+        while !game.is_terminal() && game.choice_number < 400 {
+            let choice = *game.get_all_choices().choose(&mut rng).unwrap();
+            game.apply_choice(&choice);
+            check(&game);
+        }
+    }
+}
+
+#[test]
+fn incremental_hash_matches_computed_hash() {
+    for rules in ["base", "all", "pit-of-misfortune,perma-death", "all,max-turns=20"] {
+        random_games(rules.parse().unwrap(), 100, |game| assert_eq!(game.hash(), game.compute_hash(), "{}", game.to_notation()));
+    }
+}
+
+#[test]
+fn hash_after_every_choice_matches_computed_hash() {
+    for (seed, moves) in [(1, 10), (2, 35), (3, 80)] {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut game = Game::new();
+        for _ in 0..moves {
+            let choice = *game.get_all_choices().choose(&mut rng).unwrap();
+            game.apply_choice(&choice);
+        }
+        for choice in game.get_all_choices() {
+            let hash = game.hash();
+            let undo = game.make(&choice);
+            assert_eq!(game.hash(), game.compute_hash(), "{} after {}", game.to_notation(), choice);
+            game.unmake(undo);
+            assert_eq!(game.hash(), hash);
+        }
+    }
+}
+
+#[test]
+fn loaded_position_has_the_same_hash() {
+    random_games(RuleSet::default(), 20, |game| {
+        assert_eq!(Game::from_notation(&game.to_notation()).unwrap().hash(), game.hash());
+    });
+}
+
+#[test]
+fn transpositions_have_the_same_hash() {
+    let mut first = Game::new();
+    let mut second = Game::new();
+    for choice in ["+S", "D>UL", "pass", "pass", "S>UR", "D>R"] {
+        first.apply_choice(&choice.parse().unwrap());
+    }
+    for choice in ["D>UL", "+S", "pass", "pass", "D>R", "S>UR"] {
+        second.apply_choice(&choice.parse().unwrap());
+    }
+    assert_eq!(first.to_notation(), second.to_notation());
+    assert_eq!(first.hash(), second.hash());
+
+    second.apply_choice(&Choice::Pass);
+    assert_ne!(first.hash(), second.hash());
+}